@@ -7,6 +7,7 @@ pub enum ToleranceError {
     ParseError(String),
     Overflow(String),
     ParseEmptyStr(&'static str),
+    Validation(String),
 }
 
 impl std::error::Error for ToleranceError {}
@@ -34,7 +35,7 @@ impl Display for ToleranceError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use ToleranceError::*;
         let text = match self {
-            ParseError(text) | Overflow(text) => text.as_str(),
+            ParseError(text) | Overflow(text) | Validation(text) => text.as_str(),
             ParseEmptyStr(type_r) => &format!("Cannot parse an empty string into {type_r}."),
         };
         write!(f, "{text}")
@@ -47,4 +48,35 @@ impl ToleranceError {
     pub fn parse_err<R>(text: impl Into<String>) -> Result<R, ToleranceError> {
         Err(Self::ParseError(text.into()))
     }
+
+    /// Returns a stable, machine-readable code of the error kind.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        use ToleranceError::*;
+        match self {
+            ParseError(_) => "E_PARSE",
+            Overflow(_) => "E_OVERFLOW",
+            ParseEmptyStr(_) => "E_EMPTY",
+            Validation(_) => "E_VALIDATION",
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::ToleranceError::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn have_a_code() {
+        assert_eq!(ParseError("no number".into()).code(), "E_PARSE");
+        assert_eq!(Overflow("too big".into()).code(), "E_OVERFLOW");
+        assert_eq!(ParseEmptyStr("T128").code(), "E_EMPTY");
+        assert_eq!(Validation("plus < minus".into()).code(), "E_VALIDATION");
+        assert_eq!(ParseError("no number".into()).to_string(), "no number");
+        assert_eq!(
+            ParseEmptyStr("T128").to_string(),
+            "Cannot parse an empty string into T128."
+        );
+    }
 }
@@ -0,0 +1,335 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{error::ToleranceError, Myth32, Myth64, Unit, T128};
+
+/// Upper bounds (inclusive) of the nominal size ranges of ISO 286-1 in mm.
+const SIZE_RANGES: [i64; 13] = [3, 6, 10, 18, 30, 50, 80, 120, 180, 250, 315, 400, 500];
+
+/// The first tolerance grade held in `IT_GRADES`.
+const FIRST_GRADE: usize = 4;
+
+/// Standard tolerance grades `IT4` to `IT14` in μ.
+#[rustfmt::skip]
+const IT_GRADES: [[i32; 13]; 11] = [
+    [  3,   4,   4,   5,   6,   7,   8,  10,  12,   14,   16,   18,   20],
+    [  4,   5,   6,   8,   9,  11,  13,  15,  18,   20,   23,   25,   27],
+    [  6,   8,   9,  11,  13,  16,  19,  22,  25,   29,   32,   36,   40],
+    [ 10,  12,  15,  18,  21,  25,  30,  35,  40,   46,   52,   57,   63],
+    [ 14,  18,  22,  27,  33,  39,  46,  54,  63,   72,   81,   89,   97],
+    [ 25,  30,  36,  43,  52,  62,  74,  87, 100,  115,  130,  140,  155],
+    [ 40,  48,  58,  70,  84, 100, 120, 140, 160,  185,  210,  230,  250],
+    [ 60,  75,  90, 110, 130, 160, 190, 220, 250,  290,  320,  360,  400],
+    [100, 120, 150, 180, 210, 250, 300, 350, 400,  460,  520,  570,  630],
+    [140, 180, 220, 270, 330, 390, 460, 540, 630,  720,  810,  890,  970],
+    [250, 300, 360, 430, 520, 620, 740, 870, 1000, 1150, 1300, 1400, 1550],
+];
+
+/// Fundamental deviations `es` of the shafts `d` to `h` in μ.
+/// The holes `D` to `H` use them mirrored as `EI = -es`.
+#[rustfmt::skip]
+const UPPER_DEVIATIONS: [(&str, [i32; 13]); 5] = [
+    ("d", [-20, -30, -40, -50, -65, -80, -100, -120, -145, -170, -190, -210, -230]),
+    ("e", [-14, -20, -25, -32, -40, -50,  -60,  -72,  -85, -100, -110, -125, -135]),
+    ("f", [ -6, -10, -13, -16, -20, -25,  -30,  -36,  -43,  -50,  -56,  -62,  -68]),
+    ("g", [ -2,  -4,  -5,  -6,  -7,  -9,  -10,  -12,  -14,  -15,  -17,  -18,  -20]),
+    ("h", [0; 13]),
+];
+
+/// Fundamental deviations `ei` of the shafts `k` to `p` in μ.
+#[rustfmt::skip]
+const LOWER_DEVIATIONS: [(&str, [i32; 13]); 4] = [
+    ("k", [0,  1,  1,  1,  2,  2,  2,  3,  3,  4,  4,  4,  5]),
+    ("m", [2,  4,  6,  7,  8,  9, 11, 13, 15, 17, 20, 21, 23]),
+    ("n", [4,  8, 10, 12, 15, 17, 20, 23, 27, 31, 34, 37, 40]),
+    ("p", [6, 12, 15, 18, 22, 26, 32, 37, 43, 50, 56, 62, 68]),
+];
+
+impl T128 {
+    /// Creates a `T128` from an ISO 286 tolerance symbol like `"H7"` or `"g6"`.
+    ///
+    /// Upper-case letters denote holes, lower-case letters shafts. Supported are nominal sizes
+    /// up to 500 mm, the grades `IT4` to `IT14`, the holes `D` to `H`, `JS` and the shafts
+    /// `d` to `h`, `js`, `k`, `m`, `n` and `p`.
+    ///
+    /// ```rust
+    /// # use tolerance::T128;
+    /// assert_eq!(T128::from_iso(50.0, "H7"), Ok(T128::new(50.0, 0.025, 0.0)));
+    /// assert_eq!(T128::from_iso(50.0, "g6"), Ok(T128::new(50.0, -0.009, -0.025)));
+    /// ```
+    pub fn from_iso(nominal: impl Into<Myth64>, symbol: &str) -> Result<T128, ToleranceError> {
+        let nominal = nominal.into();
+        let not_supported =
+            || ToleranceError::ParseError(format!("ISO 286 symbol '{symbol}' is not supported!"));
+        let range = SIZE_RANGES
+            .iter()
+            .position(|&r| nominal <= Myth64::from(r * Unit::MM))
+            .filter(|_| nominal.is_positive())
+            .ok_or_else(|| {
+                ToleranceError::ParseError(format!(
+                    "ISO 286 is not supported for a nominal size of {nominal} mm!"
+                ))
+            })?;
+        let split = symbol
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(not_supported)?;
+        let (letters, grade) = symbol.split_at(split);
+        let grade: usize = grade.parse().map_err(|_| not_supported())?;
+        let it = grade
+            .checked_sub(FIRST_GRADE)
+            .and_then(|g| IT_GRADES.get(g))
+            .ok_or_else(not_supported)?[range]
+            * Unit::MY;
+        let hole = if letters.chars().all(|c| c.is_ascii_uppercase()) {
+            true
+        } else if letters.chars().all(|c| c.is_ascii_lowercase()) {
+            false
+        } else {
+            return Err(not_supported());
+        };
+        let letters = letters.to_ascii_lowercase();
+        let (upper, lower) = if letters == "js" {
+            (it / 2, -(it / 2))
+        } else if let Some((_, es)) = UPPER_DEVIATIONS.iter().find(|(l, _)| *l == letters) {
+            let es = es[range] * Unit::MY;
+            if hole {
+                (it - es, -es)
+            } else {
+                (es, es - it)
+            }
+        } else if let Some((_, ei)) = LOWER_DEVIATIONS.iter().find(|(l, _)| *l == letters) {
+            if hole {
+                return Err(not_supported());
+            }
+            // `k` has no deviation beside the grades IT4 to IT7.
+            let ei = if letters == "k" && !(4..=7).contains(&grade) {
+                0
+            } else {
+                ei[range] * Unit::MY
+            };
+            (ei + it, ei)
+        } else {
+            return Err(not_supported());
+        };
+        Ok(T128::new(nominal, Myth32::from(upper), Myth32::from(lower)))
+    }
+}
+
+impl T128 {
+    /// Creates the hole tolerance giving a clearance between `min_clear` and `max_clear` with
+    /// the mating `shaft`.
+    ///
+    /// Fails, if the shaft tolerance is wider than the requested clearance range.
+    ///
+    /// ```rust
+    /// # use tolerance::{Myth64, T128};
+    /// let shaft = T128::new(50.0, -0.009, -0.025);
+    /// let hole = T128::hole_for_clearance(shaft, Myth64::from(0.01), Myth64::from(0.05)).unwrap();
+    /// assert_eq!(hole.lower_limit(), Myth64::from(50.001));
+    /// assert_eq!(hole.upper_limit(), Myth64::from(50.025));
+    /// ```
+    pub fn hole_for_clearance(
+        shaft: T128,
+        min_clear: Myth64,
+        max_clear: Myth64,
+    ) -> Result<T128, ToleranceError> {
+        let lower = shaft.upper_limit() + min_clear;
+        let upper = shaft.lower_limit() + max_clear;
+        if lower > upper {
+            return Err(ToleranceError::Validation(format!(
+                "A clearance of {min_clear} to {max_clear} can't be reached with the shaft {shaft}."
+            )));
+        }
+        T128::from_limits(lower, upper)
+    }
+}
+
+/// Creates the hole and the shaft tolerance of a fit from its ISO 286 symbols.
+///
+/// ```rust
+/// # use tolerance::{fit_pair, T128};
+/// let (hole, shaft) = fit_pair(50.0, "H7", "g6").unwrap();
+/// assert_eq!(hole, T128::new(50.0, 0.025, 0.0));
+/// assert_eq!(shaft, T128::new(50.0, -0.009, -0.025));
+/// ```
+pub fn fit_pair(
+    nominal: impl Into<Myth64>,
+    hole_symbol: &str,
+    shaft_symbol: &str,
+) -> Result<(T128, T128), ToleranceError> {
+    let nominal = nominal.into();
+    if !hole_symbol.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return ToleranceError::parse_err(format!("'{hole_symbol}' is not a hole symbol!"));
+    }
+    if !shaft_symbol.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return ToleranceError::parse_err(format!("'{shaft_symbol}' is not a shaft symbol!"));
+    }
+    Ok((
+        T128::from_iso(nominal, hole_symbol)?,
+        T128::from_iso(nominal, shaft_symbol)?,
+    ))
+}
+
+/// Returns the `(min_clearance, max_clearance)` between a `hole` and a `shaft`.
+/// A negative clearance is an interference.
+///
+/// ```rust
+/// # use tolerance::{fit_clearance, Myth64, T128};
+/// let hole = T128::new(50.0, 0.025, 0.0);
+/// let shaft = T128::new(50.0, -0.009, -0.025);
+/// assert_eq!(fit_clearance(&hole, &shaft), (Myth64::from(0.009), Myth64::from(0.05)));
+/// ```
+pub fn fit_clearance(hole: &T128, shaft: &T128) -> (Myth64, Myth64) {
+    (
+        hole.lower_limit() - shaft.upper_limit(),
+        hole.upper_limit() - shaft.lower_limit(),
+    )
+}
+
+/// The kind of a fit between a hole and a shaft.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Fit {
+    /// There is always a clearance (or contact), the minimal clearance is `>= 0`.
+    Clearance,
+    /// Depending on the actual sizes there is a clearance or an interference.
+    Transition,
+    /// There is always an interference, the maximal clearance is `<= 0`.
+    Interference,
+}
+
+impl Fit {
+    /// Classifies the fit of a `hole` and a `shaft` by their [`fit_clearance`].
+    ///
+    /// ```rust
+    /// # use tolerance::{fit_pair, Fit};
+    /// let (hole, shaft) = fit_pair(50.0, "H7", "p6").unwrap();
+    /// assert_eq!(Fit::of(&hole, &shaft), Fit::Interference);
+    /// ```
+    #[must_use]
+    pub fn of(hole: &T128, shaft: &T128) -> Fit {
+        match fit_clearance(hole, shaft) {
+            (min, _) if min >= Myth64::ZERO => Fit::Clearance,
+            (_, max) if max <= Myth64::ZERO => Fit::Interference,
+            _ => Fit::Transition,
+        }
+    }
+}
+
+impl Display for Fit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Fit::Clearance => "Clearance fit",
+            Fit::Transition => "Transition fit",
+            Fit::Interference => "Interference fit",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Returns a line for reports with the [`Fit`] and its range in mm: the clearance for a
+/// clearance or transition fit (negative is an interference), the interference for an
+/// interference fit.
+///
+/// ```rust
+/// # use tolerance::{fit_pair, fit_summary};
+/// let (hole, shaft) = fit_pair(50.0, "H7", "g6").unwrap();
+/// assert_eq!(fit_summary(hole, shaft), "Clearance fit: 0.009\u{2013}0.050 mm");
+/// ```
+pub fn fit_summary(hole: T128, shaft: T128) -> String {
+    let fit = Fit::of(&hole, &shaft);
+    let (min, max) = match (fit, fit_clearance(&hole, &shaft)) {
+        (Fit::Interference, (min, max)) => (-max, -min),
+        (_, clearance) => clearance,
+    };
+    format!("{fit}: {min:.3}\u{2013}{max:.3} mm")
+}
+
+#[cfg(test)]
+mod should {
+    use super::{fit_clearance, fit_pair, fit_summary, Fit};
+    use crate::{Myth64, T128};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_iso_symbols() {
+        assert_eq!(T128::from_iso(25.0, "H7"), Ok(T128::new(25.0, 0.021, 0.0)));
+        assert_eq!(T128::from_iso(25.0, "h6"), Ok(T128::new(25.0, 0.0, -0.013)));
+        assert_eq!(T128::from_iso(25.0, "F8"), Ok(T128::new(25.0, 0.053, 0.02)));
+        assert_eq!(
+            T128::from_iso(25.0, "js6"),
+            Ok(T128::with_sym(25.0, 0.0065))
+        );
+        assert_eq!(
+            T128::from_iso(25.0, "k6"),
+            Ok(T128::new(25.0, 0.015, 0.002))
+        );
+        assert_eq!(T128::from_iso(25.0, "k8"), Ok(T128::new(25.0, 0.033, 0.0)));
+        assert_eq!(T128::from_iso(3.0, "p6"), Ok(T128::new(3.0, 0.012, 0.006)));
+        assert!(T128::from_iso(25.0, "K7").is_err());
+        assert!(T128::from_iso(25.0, "x7").is_err());
+        assert!(T128::from_iso(25.0, "H3").is_err());
+        assert!(T128::from_iso(25.0, "Hh7").is_err());
+        assert!(T128::from_iso(600.0, "H7").is_err());
+        assert!(T128::from_iso(0.0, "H7").is_err());
+    }
+
+    #[test]
+    fn hole_for_clearance() {
+        let shaft = T128::new(25.0, -0.02, -0.033);
+        let (min, max) = (Myth64::from(0.02), Myth64::from(0.074));
+        let hole = T128::hole_for_clearance(shaft, min, max).unwrap();
+        assert_eq!(hole.lower_limit() - shaft.upper_limit(), min);
+        assert_eq!(hole.upper_limit() - shaft.lower_limit(), max);
+        assert_eq!(hole, T128::new(250_205, 205, -205));
+
+        let hole = T128::hole_for_clearance(shaft, min, Myth64::from(0.033)).unwrap();
+        assert_eq!(hole.width(), Myth64::ZERO);
+        assert!(T128::hole_for_clearance(shaft, min, Myth64::from(0.032)).is_err());
+    }
+
+    #[test]
+    fn classify_fits() {
+        let (hole, shaft) = fit_pair(25.0, "H7", "g6").unwrap();
+        assert_eq!(
+            fit_clearance(&hole, &shaft),
+            (Myth64::from(0.007), Myth64::from(0.041))
+        );
+        assert_eq!(Fit::of(&hole, &shaft), Fit::Clearance);
+        let (hole, shaft) = fit_pair(25.0, "H7", "k6").unwrap();
+        assert_eq!(
+            fit_clearance(&hole, &shaft),
+            (Myth64::from(-0.015), Myth64::from(0.019))
+        );
+        assert_eq!(Fit::of(&hole, &shaft), Fit::Transition);
+        let (hole, shaft) = fit_pair(25.0, "H7", "p6").unwrap();
+        assert_eq!(
+            fit_clearance(&hole, &shaft),
+            (Myth64::from(-0.035), Myth64::from(-0.001))
+        );
+        assert_eq!(Fit::of(&hole, &shaft), Fit::Interference);
+        // touching limits still are a clearance fit
+        let (hole, shaft) = fit_pair(25.0, "H7", "h6").unwrap();
+        assert_eq!(Fit::of(&hole, &shaft), Fit::Clearance);
+    }
+
+    #[test]
+    fn summarize_fits() {
+        let summary = |shaft: &str| {
+            let (hole, shaft) = fit_pair(25.0, "H7", shaft).unwrap();
+            fit_summary(hole, shaft)
+        };
+        assert_eq!(summary("g6"), "Clearance fit: 0.007\u{2013}0.041 mm");
+        assert_eq!(summary("k6"), "Transition fit: -0.015\u{2013}0.019 mm");
+        assert_eq!(summary("p6"), "Interference fit: 0.001\u{2013}0.035 mm");
+    }
+
+    #[test]
+    fn create_fit_pair() {
+        let (hole, shaft) = fit_pair(50.0, "H7", "g6").unwrap();
+        assert_eq!(hole, T128::new(50.0, 0.025, 0.0));
+        assert_eq!(shaft, T128::new(50.0, -0.009, -0.025));
+        // a clearance fit
+        assert!(hole.lower_limit() > shaft.upper_limit());
+
+        assert!(fit_pair(50.0, "g6", "H7").is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Myth64, T128};
+
+/// # Closed interval of `Myth64`s
+///
+/// Classic interval arithmetic for propagating uncertainties: every operation returns the
+/// smallest interval containing all possible results of the operands.
+///
+/// Unlike [`T128`](./struct.T128.html) an `Interval` has no nominal value, it only knows its
+/// limits. Converting a `T128` keeps its limits and drops the nominal.
+///
+/// ```rust
+/// # use tolerance::Interval;
+/// let a = Interval::new(1.0, 2.0);
+/// let b = Interval::new(0.5, 0.75);
+///
+/// assert_eq!(a + b, Interval::new(1.5, 2.75));
+/// assert_eq!(a - b, Interval::new(0.25, 1.5));
+/// assert_eq!(format!("{}", a - b), "[0.25, 1.5]");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[must_use]
+pub struct Interval {
+    lower: Myth64,
+    upper: Myth64,
+}
+
+impl Interval {
+    /// Creates an `Interval` between the two limits, their order doesn't matter.
+    pub fn new(a: impl Into<Myth64>, b: impl Into<Myth64>) -> Self {
+        let (a, b) = (a.into(), b.into());
+        Interval {
+            lower: a.min(b),
+            upper: a.max(b),
+        }
+    }
+
+    /// Creates an `Interval` holding only `value`.
+    pub fn point(value: impl Into<Myth64>) -> Self {
+        let value = value.into();
+        Interval {
+            lower: value,
+            upper: value,
+        }
+    }
+
+    /// Returns the lower limit.
+    pub const fn lower(&self) -> Myth64 {
+        self.lower
+    }
+
+    /// Returns the upper limit.
+    pub const fn upper(&self) -> Myth64 {
+        self.upper
+    }
+
+    /// Returns the distance between the limits.
+    pub fn width(&self) -> Myth64 {
+        self.upper - self.lower
+    }
+
+    /// Checks if `value` is inside of the limits (inclusive).
+    pub fn contains(&self, value: impl Into<Myth64>) -> bool {
+        let value = value.into();
+        self.lower <= value && value <= self.upper
+    }
+}
+
+impl From<T128> for Interval {
+    fn from(t: T128) -> Self {
+        Interval::new(t.lower_limit(), t.upper_limit())
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        Display::fmt(&self.lower, f)?;
+        write!(f, ", ")?;
+        Display::fmt(&self.upper, f)?;
+        write!(f, "]")
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        Interval {
+            lower: self.lower + other.lower,
+            upper: self.upper + other.upper,
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    /// `[a, b] - [c, d] = [a - d, b - c]`
+    fn sub(self, other: Interval) -> Interval {
+        Interval {
+            lower: self.lower - other.upper,
+            upper: self.upper - other.lower,
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    /// Multiplies the values in mm (`Myth64::ONE` is neutral), the limits are rounded outwards.
+    ///
+    /// ### Panics
+    /// If a limit of the product does not fit into a `Myth64`.
+    fn mul(self, other: Interval) -> Interval {
+        let one = i128::from(Myth64::ONE.0);
+        let products = [
+            i128::from(self.lower.0) * i128::from(other.lower.0),
+            i128::from(self.lower.0) * i128::from(other.upper.0),
+            i128::from(self.upper.0) * i128::from(other.lower.0),
+            i128::from(self.upper.0) * i128::from(other.upper.0),
+        ];
+        let min = products.iter().min().unwrap().div_euclid(one);
+        let max = -(-products.iter().max().unwrap()).div_euclid(one);
+        let limit = |v: i128| Myth64::try_from(v).expect("Interval product out of scope");
+        Interval {
+            lower: limit(min),
+            upper: limit(max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::Interval;
+    use crate::{Myth64, T128};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn create() {
+        assert_eq!(Interval::new(2.0, 1.0), Interval::new(1.0, 2.0));
+        let i = Interval::from(T128::new(10.0, 0.1, -0.2));
+        assert_eq!(i.lower(), Myth64::from(9.8));
+        assert_eq!(i.upper(), Myth64::from(10.1));
+        assert_eq!(i.width(), Myth64::from(0.3));
+        assert!(i.contains(9.8));
+        assert!(i.contains(10.1));
+        assert!(!i.contains(10.1001));
+        assert!(Interval::point(3.0).contains(3.0));
+    }
+
+    #[test]
+    fn subtract_like_tolerances() {
+        let a = T128::new(10.0, 0.1, -0.2);
+        let b = T128::new(4.0, 0.05, 0.0);
+        // Both propagate the limits the same way ...
+        let interval = Interval::from(a) - Interval::from(b);
+        assert_eq!(interval, Interval::new(5.75, 6.1));
+        assert_eq!(interval, Interval::from(a - b));
+        // ... but only the tolerance keeps its nominal value
+        assert_eq!(a - b, T128::new(6.0, 0.1, -0.25));
+        // and an interval is never mirrored like an inverted tolerance.
+        assert_eq!(!a, T128::new(-10.0, 0.2, -0.1));
+        assert_eq!(
+            Interval::point(0.0) - Interval::from(a),
+            Interval::new(-10.1, -9.8)
+        );
+    }
+
+    #[test]
+    fn multiply() {
+        let a = Interval::new(2.0, 3.0);
+        assert_eq!(a * Interval::new(-1.0, 4.0), Interval::new(-3.0, 12.0));
+        assert_eq!(a * Interval::point(1.0), a);
+        assert_eq!(
+            Interval::new(-0.0001, 0.0001) * Interval::point(0.5),
+            Interval::new(-0.0001, 0.0001)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Interval::new(-1.5, 2.0)), "[-1.5, 2.0]");
+        assert_eq!(format!("{:.2}", Interval::new(-1.5, 2.0)), "[-1.50, 2.00]");
+    }
+}
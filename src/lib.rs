@@ -8,14 +8,23 @@
 #![doc = include_str!("../README.md")]
 
 pub mod error;
+mod fit;
+mod interval;
 mod myths;
+#[cfg(feature = "bytemuck")]
+mod pod;
 mod tols;
 mod unit;
 
 pub use self::unit::*;
+pub use fit::*;
+pub use interval::*;
+pub use myths::length_counter::LengthCounter;
 pub use myths::myth16::*;
 pub use myths::myth32::*;
 pub use myths::myth64::*;
+pub use myths::quantized::Quantized;
+pub use myths::RoundingMode;
 pub use tols::tol128::*;
 pub use tols::tol64::*;
 
@@ -24,12 +33,50 @@ use error::ToleranceError;
 #[cfg(feature = "serde")]
 include!("tols/serde.rs");
 
+/// Creates a [`Myth64`] from a number of mm.
+///
+/// ```rust
+/// # use tolerance::{mm, Myth64};
+/// assert_eq!(mm!(12.5), Myth64::from(12.5));
+/// assert_eq!(mm!(-3), Myth64::from(-3));
+/// ```
+#[macro_export]
+macro_rules! mm {
+    ($value:expr) => {
+        $crate::Myth64::from($value)
+    };
+}
+
+/// Creates a [`T128`] from a value and its tolerance in mm, either as `plus` and `minus`
+/// or as a symmetric tolerance.
+///
+/// ```rust
+/// # use tolerance::{tol, T128};
+/// assert_eq!(tol!(100.0, +0.05, -0.2), T128::new(100.0, 0.05, -0.2));
+/// assert_eq!(tol!(100.0, 0.05, -0.2), T128::new(100.0, 0.05, -0.2));
+/// assert_eq!(tol!(100.0, 0.1), T128::with_sym(100.0, 0.1));
+/// ```
+#[macro_export]
+macro_rules! tol {
+    ($value:expr, $(+)? $plus:expr, $minus:expr $(,)?) => {
+        $crate::T128::new($value, $plus, $minus)
+    };
+    ($value:expr, $(+)? $tol:expr $(,)?) => {
+        $crate::T128::with_sym($value, $tol)
+    };
+}
+
 #[inline]
 fn str2int(bytes: &[u8], t_type: &str) -> Result<i64, ToleranceError> {
     let mut v = 0i64;
     for c in bytes {
         match c {
-            0x30..=0x39 => v = v * 10 + i64::from(c - 0x30),
+            0x30..=0x39 => {
+                v = v
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(i64::from(c - 0x30)))
+                    .ok_or_else(|| ToleranceError::Overflow(format!("Number to big for {t_type}")))?
+            }
             _ => {
                 return Err(ToleranceError::ParseError(format!(
                     "Found ascii #{c} (a non-numerical literal) in input, can't parse input into a {t_type}!",
@@ -43,14 +90,85 @@ fn str2int(bytes: &[u8], t_type: &str) -> Result<i64, ToleranceError> {
 /// helper-method used from all types.
 #[inline]
 pub(crate) fn try_from_str(value: &str, t_type: &'static str) -> Result<i64, ToleranceError> {
+    parse_number(value, Unit::MM, t_type)
+}
+
+/// Like `try_from_str` but accepts a unit suffix like `"1.5in"`, without one `default` is used.
+pub(crate) fn try_from_str_in(
+    value: &str,
+    default: Unit,
+    t_type: &'static str,
+) -> Result<i64, ToleranceError> {
+    let (number, unit) = split_unit(value.trim())?;
+    parse_number(number.trim_end(), unit.unwrap_or(default), t_type)
+}
+
+/// Splits a trailing unit suffix from `value`.
+pub(crate) fn split_unit(value: &str) -> Result<(&str, Option<Unit>), ToleranceError> {
+    let number = value.trim_end_matches(char::is_alphabetic);
+    let suffix = &value[number.len()..];
+    if suffix.is_empty() {
+        return Ok((value, None));
+    }
+    Unit::from_suffix(suffix)
+        .map(|unit| (number, Some(unit)))
+        .ok_or_else(|| ToleranceError::ParseError(format!("Unknown unit '{suffix}' in '{value}'!")))
+}
+
+/// Moves the decimal point of `number` by `exp` places, `"1.5"` by `1` is `"15."`.
+///
+/// The shift is limited to 30 places, beyond any non-zero number overflows or is
+/// truncated to zero anyway.
+fn shift_point(number: &str, exp: i32) -> String {
+    let (sign, number) = match number.strip_prefix(['-', '+']) {
+        Some(rest) => (&number[..1], rest),
+        None => ("", number),
+    };
+    let (base, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let digits = format!("{base}{fraction}");
+    let point = base.len() as i64 + i64::from(exp.clamp(-30, 30));
+    if point <= 0 {
+        format!(
+            "{sign}0.{}{digits}",
+            "0".repeat(point.unsigned_abs() as usize)
+        )
+    } else if point as usize >= digits.len() {
+        format!(
+            "{sign}{digits}{}",
+            "0".repeat(point as usize - digits.len())
+        )
+    } else {
+        let (base, fraction) = digits.split_at(point as usize);
+        format!("{sign}{base}.{fraction}")
+    }
+}
+
+/// Parses a decimal number in the given `unit`, fractions smaller than 0.1μ are truncated.
+fn parse_number(value: &str, unit: Unit, t_type: &'static str) -> Result<i64, ToleranceError> {
     let value = value.trim();
     if value.is_empty() {
         return Err(ToleranceError::ParseEmptyStr(t_type));
     }
-    let (base, fraction) = value.split_once('.').unwrap_or((value, "0"));
+    let shifted;
+    let number = match value.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => {
+            if !mantissa.bytes().any(|c| c.is_ascii_digit()) {
+                return Err(ToleranceError::ParseError(format!(
+                    "Missing number before exponent in '{value}'"
+                )));
+            }
+            let exp: i32 = exp.parse().map_err(|_| {
+                ToleranceError::ParseError(format!("Not a valid exponent in '{value}'"))
+            })?;
+            shifted = shift_point(mantissa, exp);
+            shifted.as_str()
+        }
+        None => value,
+    };
+    let (base, fraction) = number.split_once('.').unwrap_or((number, "0"));
     let mut base = base.as_bytes();
     let &c = base.first().unwrap_or(&b'0');
-    let sign = 1 - i64::from(c == b'-') * 2;
+    let sign = 1 - i128::from(c == b'-') * 2;
     if c == b'-' || c == b'+' {
         base = &base[1..];
     }
@@ -59,7 +177,11 @@ pub(crate) fn try_from_str(value: &str, t_type: &'static str) -> Result<i64, Tol
             "Not a valid Number: '{value}'"
         )));
     }
-    let fraction = fraction.to_string() + "00000";
-    let fraction = fraction.split_at(4).0.as_bytes();
-    Ok((str2int(base, t_type)? * Unit::MM + str2int(fraction, t_type)?) * sign)
+    let fraction = &fraction.as_bytes()[..fraction.len().min(9)];
+    let scale = 10i128.pow(fraction.len() as u32);
+    let unit = i128::from(*unit);
+    let v = i128::from(str2int(base, t_type)?) * unit
+        + i128::from(str2int(fraction, t_type)?) * unit / scale;
+    i64::try_from(v * sign)
+        .map_err(|_| ToleranceError::Overflow(format!("{value} is to big for {t_type}")))
 }
@@ -1,6 +1,20 @@
+pub(crate) mod length_counter;
 pub(crate) mod myth16;
 pub(crate) mod myth32;
 pub(crate) mod myth64;
+pub(crate) mod quantized;
+
+/// Defines how ties are resolved while rounding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds half-way cases away from zero, like [`round`](struct.Myth64.html#method.round).
+    #[default]
+    HalfUp,
+    /// Rounds half-way cases towards zero.
+    HalfDown,
+    /// Rounds half-way cases to the nearest even number (banker's rounding).
+    HalfEven,
+}
 
 macro_rules! from_number {
     ($Self:ident, $($Target:ident),+) => {
@@ -38,6 +52,25 @@ macro_rules! from_myths {
     }
 }
 
+macro_rules! saturating_into_myths {
+    ($Self:ident, $($name:ident => $Target:ident),+) => {
+        impl $Self {
+            $(
+                #[doc = concat!("Converts into a `", stringify!($Target), "`, clamping to `", stringify!($Target), "::MIN` and `", stringify!($Target), "::MAX`.")]
+                pub const fn $name(&self) -> $Target {
+                    if self.0 > $Target::MAX.0 as _ {
+                        $Target::MAX
+                    } else if self.0 < $Target::MIN.0 as _ {
+                        $Target::MIN
+                    } else {
+                        $Target(self.0 as _)
+                    }
+                }
+            )+
+        }
+    }
+}
+
 macro_rules! try_from_number {
     ($Self:ident, $($Target:ident),+) => {
         $(
@@ -58,9 +91,8 @@ macro_rules! try_from_myths {
             impl TryFrom<$Target> for $Self {
                 type Error = ToleranceError;
 
-                #[allow(clippy::needless_question_mark)]
                 fn try_from(value: $Target) -> Result<Self, Self::Error> {
-                    Ok(Self::try_from(value.0)?)
+                    Ok(Self(value.0.try_into()?))
                 }
             }
         )+
@@ -163,12 +195,197 @@ macro_rules! standard_myths {
                 self.0 as f64 / $Self::ONE.0 as f64
             }
 
+            /// Converts a value in mm like `From<f64>`, but fails on a number not being finite or
+            /// beyond the limits of the type instead of panicking or saturating.
+            pub(crate) fn try_from_mm(mm: f64) -> Result<Self, ToleranceError> {
+                if !mm.is_finite() {
+                    return Err(ToleranceError::ParseError(format!("{mm} is not a number")));
+                }
+                if mm < $Self::MIN.as_f64() || mm > $Self::MAX.as_f64() {
+                    return Err(ToleranceError::Overflow(format!("{mm} is to big for {}", stringify!($Self))));
+                }
+                Ok($Self::from(mm))
+            }
+
             /// Returns the value in the given `Unit`.
             #[must_use]
             pub fn as_unit(&self, unit: Unit) -> f64 {
                 self.0 as f64 / *unit as f64
             }
 
+            /// Formats the value in the given `unit` followed by its [symbol](./struct.Unit.html#method.symbol).
+            /// All digits down to 0.1μ are shown, trailing zeros are cut off.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::{", stringify!($Self), ", Unit};")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::from(2.54).format_in(Unit::INCH), \"0.1 in\");")]
+            /// ```
+            #[must_use]
+            pub fn format_in(&self, unit: Unit) -> String {
+                let prec = (*unit as f64).log10().ceil() as usize;
+                let s = format!("{:.prec$}", self.as_unit(unit));
+                let number = if s.contains('.') {
+                    s.trim_end_matches('0').trim_end_matches('.')
+                } else {
+                    &s
+                };
+                match unit.symbol() {
+                    Some(symbol) => format!("{number} {symbol}"),
+                    None => number.to_string(),
+                }
+            }
+
+            /// Formats the value in the given `unit` with `prec` decimal places followed by its
+            /// [symbol](./struct.Unit.html#method.symbol).
+            #[must_use]
+            pub fn format_in_prec(&self, unit: Unit, prec: usize) -> String {
+                let number = format!("{:.prec$}", self.as_unit(unit));
+                match unit.symbol() {
+                    Some(symbol) => format!("{number} {symbol}"),
+                    None => number,
+                }
+            }
+
+            /// Returns the value in nanometer.
+            ///
+            /// There is no `Unit` for nanometer, it is smaller than the resolution of 0.1μ, so
+            /// the result always is a multiple of 100.
+            #[must_use]
+            pub fn as_nm(&self) -> f64 {
+                self.0 as f64 * 100.0
+            }
+
+            /// Returns how many `unit`s this value is, like [`as_unit`](#method.as_unit).
+            ///
+            /// ### Panics
+            /// If `unit` is zero.
+            #[must_use]
+            pub fn count_of(&self, unit: Unit) -> f64 {
+                assert!(*unit != 0, "Can't count a zero unit.");
+                self.as_unit(unit)
+            }
+
+            /// Returns the value in "mm" as a half precision float.
+            ///
+            /// ⚠ A `f16` holds only 11 significant bits: the relative error is up to 0.05%,
+            /// so values above 2 mm lose the 0.1μ precision, above 65504 mm it is infinite.
+            #[cfg(feature = "half")]
+            #[must_use]
+            pub fn as_f16(&self) -> half::f16 {
+                half::f16::from_f64(self.as_f64())
+            }
+
+            /// Creates a value from a half precision float in "mm", rounded to 0.1μ.
+            ///
+            #[doc = concat!("Values beyond the limits saturate to `", stringify!($Self), "::MIN`/`MAX`, `NaN` becomes `ZERO`.")]
+            #[cfg(feature = "half")]
+            pub fn saturating_from_f16(value: half::f16) -> Self {
+                Self((f64::from(value) * $Self::ONE.0 as f64).round() as $typ)
+            }
+
+            /// Returns the coarsest metric `Unit` in which the value is a whole number, from
+            /// `Unit::KM` down to `Unit::MY` or `Unit::potency(0)` (0.1μ).
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::{", stringify!($Self), ", Unit};")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::from(2.0).natural_unit(), Unit::MM);")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::from(0.0025).natural_unit(), Unit::potency(0));")]
+            /// ```
+            #[must_use]
+            pub fn natural_unit(&self) -> Unit {
+                [Unit::KM, Unit::METER, Unit::CM, Unit::MM, Unit::MY]
+                    .into_iter()
+                    .find(|unit| self.as_i64() % **unit == 0)
+                    .unwrap_or(Unit::potency(0))
+            }
+
+            /// Writes the value in "mm" with `decimals` (at most 4) into `out` without allocating,
+            /// like `format!("{:.decimals$}")`.
+            pub fn write_mm(&self, out: &mut impl std::fmt::Write, decimals: usize) -> std::fmt::Result {
+                let decimals = decimals.min(4);
+                let v = self.round(Unit::potency(4 - decimals)).0 as i64;
+                let one = $Self::ONE.0 as i64;
+                let sign = if v < 0 { "-" } else { "" };
+                let (int, fraction) = ((v / one).unsigned_abs(), (v % one).unsigned_abs());
+                if decimals == 0 {
+                    write!(out, "{sign}{int}")
+                } else {
+                    let fraction = fraction / 10u64.pow(4 - decimals as u32);
+                    write!(out, "{sign}{int}.{fraction:0decimals$}")
+                }
+            }
+
+            /// Returns the raw value in 0.1μ as a string in the given `radix`, like `"-7a"`.
+            ///
+            /// ### Panics
+            /// If `radix` is not in `2..=36`.
+            #[must_use]
+            pub fn to_radix_string(&self, radix: u32) -> String {
+                assert!((2..=36).contains(&radix), "Radix {radix} is not in 2..=36.");
+                let mut v = self.0.unsigned_abs() as u128;
+                let mut digits = Vec::new();
+                loop {
+                    digits.push(std::char::from_digit((v % radix as u128) as u32, radix).unwrap());
+                    v /= radix as u128;
+                    if v == 0 {
+                        break;
+                    }
+                }
+                if self.0 < 0 {
+                    digits.push('-');
+                }
+                digits.iter().rev().collect()
+            }
+
+            /// Parses the raw value in 0.1μ from a string in the given `radix`,
+            /// the reverse of [`to_radix_string`](#method.to_radix_string).
+            pub fn from_radix_str(value: &str, radix: u32) -> Result<Self, ToleranceError> {
+                if !(2..=36).contains(&radix) {
+                    return Err(ToleranceError::ParseError(format!("Radix {radix} is not in 2..=36.")));
+                }
+                $typ::from_str_radix(value, radix).map(Self).map_err(|e| {
+                    ToleranceError::ParseError(format!(
+                        "Can't parse '{value}' with radix {radix} into a {}: {e}",
+                        stringify!($Self)
+                    ))
+                })
+            }
+
+            /// Returns the value in "mm" with always four decimals, like `"1.2000"`.
+            #[must_use]
+            pub fn to_mm4(&self) -> String {
+                format!("{self:.4}")
+            }
+
+            /// Creates the value of `count` times `unit`, e.g. 5 inches.
+            ///
+            #[doc = concat!("Fails, if the value does not fit into a `", stringify!($Self), "`.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::{", stringify!($Self), ", Unit};")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::from_unit_value(1, Unit::MM), Ok(", stringify!($Self), "::ONE));")]
+            /// ```
+            pub fn from_unit_value(count: i64, unit: Unit) -> Result<Self, ToleranceError> {
+                $Self::try_from(count as i128 * *unit as i128).map_err(|_| {
+                    ToleranceError::Overflow(format!("{count} * {unit:?} is to big for {}", stringify!($Self)))
+                })
+            }
+
+            /// Returns the running totals of `segments`, the element `i` is the sum of `segments[..=i]`.
+            ///
+            /// Summed up in `i128`, fails if a total doesn't fit into
+            #[doc = concat!("a `", stringify!($Self), "`.")]
+            pub fn cumulative_sum(segments: &[$Self]) -> Result<Vec<$Self>, ToleranceError> {
+                let mut total = 0i128;
+                segments
+                    .iter()
+                    .map(|s| {
+                        total += s.0 as i128;
+                        $Self::try_from(total)
+                    })
+                    .collect()
+            }
+
             /// Rounds to the given Unit.
             pub fn round(&self, unit: Unit) -> Self {
                 if *unit == 0 {
@@ -184,15 +401,204 @@ macro_rules! standard_myths {
                 }
             }
 
+            /// Rounds to the given Unit like [`round`](#method.round), but a tie is rounded
+            /// toward the `reference` instead of away from zero.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::{", stringify!($Self), ", Unit};")]
+            #[doc = concat!("let m = ", stringify!($Self), "::from(1.5);")]
+            #[doc = concat!("assert_eq!(m.round_toward(Unit::MM, ", stringify!($Self), "::ZERO), ", stringify!($Self), "::from(1.0));")]
+            /// ```
+            pub fn round_toward(&self, unit: Unit, reference: $Self) -> Self {
+                if *unit == 0 {
+                    return *self;
+                }
+                let (down, up) = (self.floor(unit), self.ceil(unit));
+                match (self.0 - down.0).cmp(&(up.0 - self.0)) {
+                    std::cmp::Ordering::Less => down,
+                    std::cmp::Ordering::Greater => up,
+                    _ if reference > *self => up,
+                    _ if reference < *self => down,
+                    _ => self.round(unit),
+                }
+            }
+
+            /// Rounds to the given Unit and returns the rounded value and the signed rounding
+            /// error `self - rounded`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::{", stringify!($Self), ", Unit};")]
+            #[doc = concat!("let m = ", stringify!($Self), "::from(1.2345);")]
+            /// let (rounded, error) = m.snap_with_error(Unit::MY);
+            #[doc = concat!("assert_eq!(rounded, ", stringify!($Self), "::from(1.235));")]
+            #[doc = concat!("assert_eq!(error, ", stringify!($Self), "::from(-0.0005));")]
+            /// assert_eq!(rounded + error, m);
+            /// ```
+            pub fn snap_with_error(&self, unit: Unit) -> (Self, Self) {
+                let rounded = self.round(unit);
+                (rounded, $Self(self.0 - rounded.0))
+            }
+
+            /// Returns the step of the last digit shown with `decimals` in mm,
+            /// e.g. `0.01` mm for 2 decimals. `decimals` is clamped to 4.
+            pub const fn step_for_precision(decimals: u32) -> Self {
+                let decimals = if decimals > 4 { 4 } else { decimals };
+                Self((10 as $typ).pow(4 - decimals))
+            }
+
+            /// Rounds to the given number of `decimals` in mm, resolving ties by `mode`.
+            ///
+            /// `decimals` is clamped to 4, the maximum precision. Rounding away from zero beyond
+            #[doc = concat!("the limits saturates to `", stringify!($Self), "::MIN`/`MAX`.")]
+            ///
+            /// ```rust
+            /// # use tolerance::{Myth64, RoundingMode};
+            /// let m = Myth64::from(1.245);
+            /// assert_eq!(m.round_mm(2, RoundingMode::HalfEven), Myth64::from(1.24));
+            /// assert_eq!(m.round_mm(2, RoundingMode::HalfUp), Myth64::from(1.25));
+            /// ```
+            pub fn round_mm(&self, decimals: u32, mode: crate::RoundingMode) -> Self {
+                use crate::RoundingMode::*;
+                let m = 10i64.pow(4 - decimals.min(4));
+                let val = self.0 as i64;
+                let clip = val % m;
+                if clip == 0 {
+                    return *self;
+                }
+                let down = Some(val - clip);
+                let away = (val - clip).checked_add(m * clip.signum());
+                let rounded = match (2 * clip.abs()).cmp(&m) {
+                    std::cmp::Ordering::Less => down,
+                    std::cmp::Ordering::Greater => away,
+                    std::cmp::Ordering::Equal => match mode {
+                        HalfUp => away,
+                        HalfDown => down,
+                        HalfEven if ((val - clip) / m) % 2 == 0 => down,
+                        HalfEven => away,
+                    },
+                };
+                let saturated = if clip < 0 { Self::MIN } else { Self::MAX };
+                rounded
+                    .and_then(|r| $typ::try_from(r).ok())
+                    .map_or(saturated, Self)
+            }
+
             /// Finds the nearest value less than or equal to an integer multiple of the given `Unit`.
             pub fn floor(&self, unit: Unit) -> Self {
                 let val = self.0;
                 let m = $typ::try_from(*unit).expect("Unit.multiply to big.");
                 let clip = val % m;
-                if val < 0 {
+                if clip < 0 {
                     Self(val - clip - m)
                 } else {
-                    Self(val - clip)}
+                    Self(val - clip)
+                }
+            }
+
+            /// Finds the nearest value greater than or equal to an integer multiple of the given `Unit`.
+            pub fn ceil(&self, unit: Unit) -> Self {
+                let val = self.0;
+                let m = $typ::try_from(*unit).expect("Unit.multiply to big.");
+                let clip = val % m;
+                if clip > 0 {
+                    Self(val - clip + m)
+                } else {
+                    Self(val - clip)
+                }
+            }
+
+            /// Returns how many whole `unit`s fit into `self` and the remainder, like `/` and `%`
+            /// in one step. Both are truncated towards zero.
+            ///
+            /// ### Panics
+            /// If `unit` is zero.
+            pub fn div_rem(&self, unit: Unit) -> (i64, Self) {
+                assert!(*unit != 0, "Can't divide by a zero unit.");
+                let val = self.0 as i64;
+                (val / *unit, Self((val % *unit) as $typ))
+            }
+
+            /// Returns how many `step`s fit between `a` and `b`, in either order.
+            ///
+            /// Returns `None`, if `step` is zero or the distance is not a multiple of `step`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let (a, b) = (", stringify!($Self), "::from(1.0), ", stringify!($Self), "::from(2.0));")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::steps_between(a, b, ", stringify!($Self), "::from(0.25)), Some(4));")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::steps_between(a, b, ", stringify!($Self), "::from(0.3)), None);")]
+            /// ```
+            #[must_use]
+            pub fn steps_between(a: $Self, b: $Self, step: $Self) -> Option<u64> {
+                let distance = (b.0 as i128 - a.0 as i128).unsigned_abs();
+                let step = (step.0 as i128).unsigned_abs();
+                if step == 0 || distance % step != 0 {
+                    return None;
+                }
+                u64::try_from(distance / step).ok()
+            }
+
+            /// Returns `true`, if `self` can be divided into `n` equal parts without a remainder
+            /// on the 0.1μ grid.
+            ///
+            /// ### Panics
+            /// If `n` is zero.
+            #[must_use]
+            pub fn divides_evenly(&self, n: i64) -> bool {
+                assert!(n != 0, "Can't divide into zero parts.");
+                (self.0 as i128) % i128::from(n) == 0
+            }
+
+            /// Checked addition, returns `None` on overflow.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                match self.0.checked_add(other.0) {
+                    Some(v) => Some(Self(v)),
+                    None => None,
+                }
+            }
+
+            /// Checked subtraction, returns `None` on overflow.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                match self.0.checked_sub(other.0) {
+                    Some(v) => Some(Self(v)),
+                    None => None,
+                }
+            }
+
+            /// Checked multiplication with a scalar, returns `None` on overflow.
+            pub const fn checked_mul(self, scalar: i64) -> Option<Self> {
+                if scalar > $typ::MAX as i64 || scalar < $typ::MIN as i64 {
+                    return if self.0 == 0 { Some(Self(0)) } else { None };
+                }
+                match self.0.checked_mul(scalar as $typ) {
+                    Some(v) => Some(Self(v)),
+                    None => None,
+                }
+            }
+
+            /// Saturating addition, clamps at `MIN` and `MAX` instead of overflowing.
+            pub const fn saturating_add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+
+            /// Saturating subtraction, clamps at `MIN` and `MAX` instead of overflowing.
+            pub const fn saturating_sub(self, other: Self) -> Self {
+                Self(self.0.saturating_sub(other.0))
+            }
+
+            /// Restricts the value to `min..=max`, like `Ord::clamp` but usable in `const`.
+            ///
+            /// ### Panics
+            /// If `min > max`.
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min.0 <= max.0, "min has to be less or equal to max.");
+                if self.0 < min.0 {
+                    min
+                } else if self.0 > max.0 {
+                    max
+                } else {
+                    self
+                }
             }
 
             /// Computes the absolute value of self.
@@ -204,11 +610,79 @@ macro_rules! standard_myths {
                 }
             }
 
+            /// Returns `self` if positive, otherwise `ZERO`.
+            pub const fn clamp_positive(&self) -> Self {
+                if self.0 < 0 {
+                    Self::ZERO
+                } else {
+                    *self
+                }
+            }
+
+            /// Returns `self` if negative, otherwise `ZERO`.
+            pub const fn clamp_negative(&self) -> Self {
+                if self.0 > 0 {
+                    Self::ZERO
+                } else {
+                    *self
+                }
+            }
+
+            /// Returns the middle between `a` and `b`, rounded towards zero like `i64::midpoint`.
+            ///
+            /// Calculated in `i128`, so it won't overflow even for `MIN` and `MAX`.
+            pub const fn midpoint(a: $Self, b: $Self) -> Self {
+                Self(((a.0 as i128 + b.0 as i128) / 2) as $typ)
+            }
+
             /// Computes the absolute difference between `self` and `other`.
             pub const fn abs_diff(self, other: $Self) -> Self {
                 Self(self.0 - other.0).abs()
             }
 
+            /// Computes the absolute difference between `self` and `other` in the given `Unit`.
+            ///
+            /// Calculated in `i128`, so it won't overflow even for `MIN` and `MAX`.
+            #[must_use]
+            pub fn abs_diff_unit(&self, other: $Self, unit: Unit) -> f64 {
+                (self.0 as i128 - other.0 as i128).abs() as f64 / *unit as f64
+            }
+
+            /// Raises the value in mm to the power of `exp`, `ONE` is the neutral element.
+            ///
+            #[doc = concat!("Calculated in `i128`, returns `None` if the result does not fit into a `", stringify!($Self), "`.")]
+            /// Digits below 0.1μ are truncated after each multiplication. `ZERO`, `ONE` and `-ONE`
+            /// return right away, for any other value the loop ends on an overflow or at zero.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let m = ", stringify!($Self), "::from(1.5);")]
+            #[doc = concat!("assert_eq!(m.checked_powi(2), Some(", stringify!($Self), "::from(2.25)));")]
+            #[doc = concat!("assert_eq!(", stringify!($Self), "::MAX.checked_powi(2), None);")]
+            /// ```
+            #[must_use]
+            pub fn checked_powi(&self, exp: u32) -> Option<Self> {
+                match self.0 {
+                    0 if exp > 0 => return Some($Self::ZERO),
+                    v if v == $Self::ONE.0 => return Some($Self::ONE),
+                    v if v == -$Self::ONE.0 && exp % 2 == 1 => return Some(*self),
+                    v if v == -$Self::ONE.0 => return Some($Self::ONE),
+                    _ => {}
+                }
+                let one = $Self::ONE.0 as i128;
+                let mut result = one;
+                for _ in 0..exp {
+                    result = result.checked_mul(self.0 as i128)? / one;
+                    if result > $typ::MAX as i128 || result < $typ::MIN as i128 {
+                        return None;
+                    }
+                    if result == 0 {
+                        break;
+                    }
+                }
+                Some($Self(result as $typ))
+            }
+
             #[doc = concat!("Returns a ", stringify!($Self) ," representing the sign of self.")]
             ///
             ///   *  0 if the number is zero
@@ -242,6 +716,18 @@ macro_rules! standard_myths {
                 self.0 == 0
             }
 
+            /// Returns the stable type tag, the name of the type.
+            #[must_use]
+            pub const fn kind(&self) -> &'static str {
+                stringify!($Self)
+            }
+
+            /// Returns the length of the byte representation, like in `to_be_bytes`.
+            #[must_use]
+            pub const fn byte_len(&self) -> usize {
+                std::mem::size_of::<$typ>()
+            }
+
             #[doc = concat!("Returns the memory representation of this ", stringify!($Self), " as a byte array in")]
             /// big-endian (network) byte order.
             #[must_use]
@@ -281,6 +767,44 @@ macro_rules! standard_myths {
                 Self($typ::from_ne_bytes(bytes))
             }
 
+            #[doc = concat!("Creates a ", stringify!($Self), " value from a big-endian byte slice.")]
+            ///
+            /// Fails if the slice has the wrong length or the decoded value is outside of
+            /// `SPEC_MIN..=SPEC_MAX`.
+            pub fn checked_from_be_bytes(bytes: &[u8]) -> Result<Self, ToleranceError> {
+                Self::from_slice(bytes, $typ::from_be_bytes)
+            }
+
+            #[doc = concat!("Creates a ", stringify!($Self), " value from a little-endian byte slice.")]
+            ///
+            /// Fails if the slice has the wrong length or the decoded value is outside of
+            /// `SPEC_MIN..=SPEC_MAX`.
+            pub fn checked_from_le_bytes(bytes: &[u8]) -> Result<Self, ToleranceError> {
+                Self::from_slice(bytes, $typ::from_le_bytes)
+            }
+
+            fn from_slice(
+                bytes: &[u8],
+                decode: fn([u8; std::mem::size_of::<$typ>()]) -> $typ,
+            ) -> Result<Self, ToleranceError> {
+                let bytes = bytes.try_into().map_err(|_| {
+                    ToleranceError::ParseError(format!(
+                        "{} needs {} bytes, got {}!",
+                        stringify!($Self),
+                        std::mem::size_of::<$typ>(),
+                        bytes.len()
+                    ))
+                })?;
+                let m = Self(decode(bytes));
+                if m < Self::SPEC_MIN || m > Self::SPEC_MAX {
+                    return Err(ToleranceError::Overflow(format!(
+                        "{m:?} is outside the range of {}",
+                        stringify!($Self)
+                    )));
+                }
+                Ok(m)
+            }
+
         }
 
         impl Debug for $Self {
@@ -315,6 +839,34 @@ macro_rules! standard_myths {
             }
         }
 
+        /// Formats the value in mm in exponential notation.
+        impl std::fmt::LowerExp for $Self {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                std::fmt::LowerExp::fmt(&self.as_f64(), f)
+            }
+        }
+
+        /// Formats the value in mm in exponential notation.
+        impl std::fmt::UpperExp for $Self {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                std::fmt::UpperExp::fmt(&self.as_f64(), f)
+            }
+        }
+
+        /// Formats the raw value in 0.1μ, for negative values the two's complement.
+        impl std::fmt::LowerHex for $Self {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                std::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        /// Formats the raw value in 0.1μ, for negative values the two's complement.
+        impl std::fmt::UpperHex for $Self {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                std::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
         impl TryFrom<&str> for $Self {
             type Error = ToleranceError;
 
@@ -331,11 +883,12 @@ macro_rules! standard_myths {
             }
         }
 
+        /// Parses a length in mm, or in the unit of a suffix like `"mm"`, `"in"` or `"thou"`.
         impl std::str::FromStr for $Self {
             type Err = ToleranceError;
 
             fn from_str(value: &str) -> Result<Self, Self::Err> {
-                crate::try_from_str(value.trim(), &stringify!($Self))
+                crate::try_from_str_in(value, Unit::MM, &stringify!($Self))
                 .and_then(|i| Self::try_from(i).
                     map_err(|_| ToleranceError::Overflow(format!("{value} is to big for {}", stringify!($Self))))
                 )
@@ -361,6 +914,13 @@ macro_rules! standard_myths {
             }
         }
 
+        /// Lossless widening into the raw "1/10 μ" value.
+        impl From<$Self> for i128 {
+            fn from(m: $Self) -> Self {
+                m.0 as i128
+            }
+        }
+
         impl From<Unit> for $Self {
             fn from(unit: Unit) -> Self {
                 $Self::try_from(*unit).expect("Unit out of scope")
@@ -382,6 +942,48 @@ macro_rules! standard_myths {
                 $Self(-self.0)
             }
         }
+
+        /// The remainder of the division by `Unit`, it takes the sign of `self` like `%` on integers.
+        impl Rem<Unit> for $Self {
+            type Output = $Self;
+
+            fn rem(self, unit: Unit) -> Self::Output {
+                Self((self.0 as i64 % *unit) as $typ)
+            }
+        }
+
+        /// Divides two lengths, the units cancel out.
+        ///
+        /// ⚠ Returns a dimensionless `f64` ratio and not a `Myth`.
+        impl Div<$Self> for $Self {
+            type Output = f64;
+
+            fn div(self, other: $Self) -> Self::Output {
+                self.0 as f64 / other.0 as f64
+            }
+        }
+
+        /// Adds a float in mm, unlike integers, which are added in 0.1μ.
+        ///
+        /// Panics like `From<f64>` on a float out of range and on an overflowing sum.
+        impl Add<f64> for $Self {
+            type Output = $Self;
+
+            fn add(self, other: f64) -> Self::Output {
+                self + $Self::from(other)
+            }
+        }
+
+        /// Subtracts a float in mm, unlike integers, which are subtracted in 0.1μ.
+        ///
+        /// Panics like `From<f64>` on a float out of range and on an overflowing difference.
+        impl Sub<f64> for $Self {
+            type Output = $Self;
+
+            fn sub(self, other: f64) -> Self::Output {
+                self - $Self::from(other)
+            }
+        }
     }
 }
 
@@ -458,6 +1060,10 @@ macro_rules! de_serde {
             where
                 D: Deserializer<'de>,
             {
+                // binary formats like `postcard` are not self-describing, they hold the raw value.
+                if !deserializer.is_human_readable() {
+                    return $typ::deserialize(deserializer).map($Self);
+                }
                 struct MythVisitor;
 
                 impl<'de> Visitor<'de> for MythVisitor {
@@ -534,6 +1140,7 @@ pub(crate) use calc_with_myths;
 pub(crate) use de_serde;
 pub(crate) use from_myths;
 pub(crate) use from_number;
+pub(crate) use saturating_into_myths;
 pub(crate) use standard_myths;
 pub(crate) use try_from_myths;
 pub(crate) use try_from_number;
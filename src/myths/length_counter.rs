@@ -0,0 +1,98 @@
+use crate::{error::ToleranceError, Myth64};
+
+/// # Running total of lengths
+///
+/// Sums up many small `Myth64` lengths, like the feed moves of a toolpath. The total is held in
+/// an `i128`, so it doesn't overflow while adding, only reading a total beyond `Myth64::MAX`
+/// fails.
+///
+/// ```rust
+/// # use tolerance::{LengthCounter, Myth64};
+/// let mut counter = LengthCounter::new();
+/// counter.add(Myth64::from(1.5));
+/// counter.add_checked(Myth64::from(0.25)).unwrap();
+/// assert!(counter.add_checked(Myth64::from(-1.0)).is_err());
+///
+/// assert_eq!(counter.total(), Ok(Myth64::from(1.75)));
+/// counter.reset();
+/// assert_eq!(counter.total(), Ok(Myth64::ZERO));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LengthCounter(i128);
+
+impl LengthCounter {
+    /// Creates a counter starting at zero.
+    pub const fn new() -> Self {
+        LengthCounter(0)
+    }
+
+    /// Adds the `length`, negative lengths are subtracted.
+    pub fn add(&mut self, length: Myth64) {
+        self.0 += i128::from(length);
+    }
+
+    /// Adds the `length`, fails with a `Validation` error on a negative length.
+    pub fn add_checked(&mut self, length: Myth64) -> Result<(), ToleranceError> {
+        if length.is_negative() {
+            return Err(ToleranceError::Validation(format!(
+                "Can't count the negative length {length}."
+            )));
+        }
+        self.add(length);
+        Ok(())
+    }
+
+    /// Returns the total, fails with an `Overflow` if it doesn't fit into a `Myth64`.
+    pub fn total(&self) -> Result<Myth64, ToleranceError> {
+        Myth64::try_from(self.0).map_err(|_| {
+            ToleranceError::Overflow(format!("The total of {} is to big for Myth64", self.0))
+        })
+    }
+
+    /// Sets the total back to zero.
+    pub fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::LengthCounter;
+    use crate::{error::ToleranceError, Myth64};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn accumulate() {
+        let mut counter = LengthCounter::default();
+        for _ in 0..1000 {
+            counter.add(Myth64(3));
+        }
+        assert_eq!(counter.total(), Ok(Myth64::from(0.3)));
+        counter.add(Myth64::from(-0.1));
+        assert_eq!(counter.total(), Ok(Myth64::from(0.2)));
+        counter.reset();
+        assert_eq!(counter, LengthCounter::new());
+    }
+
+    #[test]
+    fn survive_overflow() {
+        let mut counter = LengthCounter::new();
+        counter.add(Myth64::MAX);
+        counter.add(Myth64::MAX);
+        assert!(matches!(counter.total(), Err(ToleranceError::Overflow(_))));
+        counter.add(-Myth64::MAX);
+        assert_eq!(counter.total(), Ok(Myth64::MAX));
+    }
+
+    #[test]
+    fn reject_negatives() {
+        let mut counter = LengthCounter::new();
+        assert_eq!(counter.add_checked(Myth64::ONE), Ok(()));
+        assert!(matches!(
+            counter.add_checked(Myth64(-1)),
+            Err(ToleranceError::Validation(_))
+        ));
+        assert_eq!(counter.add_checked(Myth64::ZERO), Ok(()));
+        assert_eq!(counter.total(), Ok(Myth64::ONE));
+    }
+}
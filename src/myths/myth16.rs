@@ -3,7 +3,7 @@ use crate::{error::ToleranceError, Myth32, Myth64, Unit};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 use std::str::FromStr;
 
 ///
@@ -40,38 +40,60 @@ use std::str::FromStr;
 ///     assert_eq!(format!("{myth:.4}"),"1.5000");
 ///     assert_eq!(format!("{myth:#}"), "15000");
 /// ```
-
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+#[repr(transparent)]
 #[must_use]
 pub struct Myth16(pub(crate) i16);
 
 impl Myth16 {
+    /// The biggest value inside the documented range of `+/- 3 mm`.
+    pub const SPEC_MAX: Myth16 = Myth16(30_000);
+    /// The smallest value inside the documented range of `+/- 3 mm`.
+    pub const SPEC_MIN: Myth16 = Myth16(-30_000);
+
     #[must_use]
     pub const fn as_i16(&self) -> i16 {
         self.0
     }
+
+    /// Creates a `Myth16` from an `i32` in 0.1μ, fails with an `Overflow` if it doesn't fit.
+    pub fn checked_from_i32(value: i32) -> Result<Myth16, ToleranceError> {
+        i16::try_from(value)
+            .map(Myth16)
+            .map_err(|_| ToleranceError::Overflow(format!("{value} is to big for Myth16")))
+    }
+
+    /// Creates a `Myth16` from an `i32` in 0.1μ, a value out of range wraps around.
+    ///
+    /// Same as `From<i32>`, see [`checked_from_i32`](#method.checked_from_i32) for the
+    /// checked path.
+    pub const fn from_i32_unchecked(value: i32) -> Myth16 {
+        Myth16(value as i16)
+    }
 }
 
 super::standard_myths!(Myth16, i16, u64, u32, u16, u8, usize, i64, i32, i16, i8, isize);
 super::from_number!(Myth16, u8, i16, i8);
-super::try_from_number!(Myth16, u64, u32, u16, i64, isize, usize);
+super::try_from_number!(Myth16, u64, u32, u16, i64, isize, usize, i128);
 super::try_from_myths!(Myth16, Myth32, Myth64);
 super::calc_with_myths!(Myth16, i16, Myth16);
 #[cfg(feature = "serde")]
 super::de_serde!(Myth16, i16);
 
-/// A potentially dangerous function.
-/// Use it for creating `Myth16` in tests or where you can control the danger.
+/// A potentially dangerous function, a value out of range wraps around.
+/// Use it for creating `Myth16` in tests or where you can control the danger, otherwise use
+/// [`Myth16::checked_from_i32`].
 impl From<i32> for Myth16 {
     fn from(value: i32) -> Self {
-        Self(value as i16)
+        Myth16::from_i32_unchecked(value)
     }
 }
 
 #[cfg(test)]
 mod should {
     use super::{Myth16, Unit};
+    use crate::error::ToleranceError;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -135,6 +157,111 @@ mod should {
         assert_eq!(format!("{m:.2}"), "0.00");
     }
 
+    #[test]
+    fn checked_from_bytes() {
+        let m = Myth16(-12_345);
+        assert_eq!(Ok(m), Myth16::checked_from_be_bytes(&m.to_be_bytes()));
+        assert_eq!(Ok(m), Myth16::checked_from_le_bytes(&m.to_le_bytes()));
+        assert!(matches!(
+            Myth16::checked_from_be_bytes(&31_000i16.to_be_bytes()),
+            Err(ToleranceError::Overflow(_))
+        ));
+        assert!(matches!(
+            Myth16::checked_from_be_bytes(&[0, 1, 2]),
+            Err(ToleranceError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn convert_i128() {
+        assert_eq!(Ok(Myth16(-12_345)), Myth16::try_from(-12_345i128));
+        assert!(Myth16::try_from(40_000i128).is_err());
+        assert_eq!(-12_345i128, i128::from(Myth16(-12_345)));
+    }
+
+    #[test]
+    fn add_sub_f64() {
+        let m = Myth16::from(1.0);
+        assert_eq!(m + 0.5, Myth16::from(1.0 + 0.5));
+        assert_eq!(m - 0.0125, Myth16::from(1.0 - 0.0125));
+        assert_eq!(m - 2.5 + 2.5, m);
+        // integers are added in 0.1μ
+        assert_eq!(m + 5, m + 0.0005);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let m = Myth16::from(1.5);
+        assert_eq!(m.checked_add(m), Some(Myth16::from(3.0)));
+        assert_eq!(Myth16::MAX.checked_add(Myth16(1)), None);
+        assert_eq!(m.checked_sub(Myth16::from(2.0)), Some(Myth16::from(-0.5)));
+        assert_eq!(Myth16::MIN.checked_sub(Myth16(1)), None);
+        assert_eq!(m.checked_mul(-2), Some(Myth16::from(-3.0)));
+        assert_eq!(Myth16::MAX.checked_mul(2), None);
+        assert_eq!(m.checked_mul(i64::MAX), None);
+        assert_eq!(Myth16::ZERO.checked_mul(i64::MAX), Some(Myth16::ZERO));
+        const SUM: Option<Myth16> = Myth16::ONE.checked_add(Myth16::ONE);
+        assert_eq!(SUM, Some(Myth16::from(2.0)));
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(Myth16::MAX.saturating_add(Myth16::from(1.0)), Myth16::MAX);
+        assert_eq!(Myth16::MIN.saturating_sub(Myth16::from(1.0)), Myth16::MIN);
+        assert_eq!(Myth16::MIN.saturating_add(Myth16::MAX), Myth16(-1));
+        assert_eq!(
+            Myth16::from(1.0).saturating_sub(Myth16::from(1.5)),
+            Myth16::from(-0.5)
+        );
+    }
+
+    #[test]
+    fn divide_ratio() {
+        assert_eq!(Myth16::from(3.0) / Myth16::from(1.2), 2.5);
+        assert_eq!(Myth16::from(-1.0) / Myth16::from(0.5), -2.0);
+        assert!((Myth16::ONE / Myth16::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn remainder() {
+        assert_eq!(Myth16::from(2.345) % Unit::MM, Myth16(3_450));
+        assert_eq!(-Myth16::from(2.345) % Unit::MM, Myth16(-3_450));
+        assert_eq!(Myth16::from(2.0) % Unit::MM, Myth16::ZERO);
+        assert_eq!(Myth16::from(-0.0125) % Unit::potency(2), Myth16(-25));
+        assert_eq!(Myth16::from(2.0) % Unit::CM, Myth16::from(2.0));
+    }
+
+    #[test]
+    fn try_from_myths() {
+        use crate::{Myth32, Myth64};
+        assert_eq!(Myth16::try_from(Myth32::from(1.5)), Ok(Myth16::from(1.5)));
+        assert!(Myth16::try_from(Myth32::from(5.0)).is_err());
+        assert!(Myth16::try_from(Myth64::from(-5.0)).is_err());
+    }
+
+    #[test]
+    fn from_i32() {
+        assert_eq!(Myth16::checked_from_i32(12_345), Ok(Myth16(12_345)));
+        assert_eq!(Myth16::checked_from_i32(-32_768), Ok(Myth16::MIN));
+        assert!(matches!(
+            Myth16::checked_from_i32(100_000),
+            Err(ToleranceError::Overflow(_))
+        ));
+        // the unchecked path wraps around
+        assert_eq!(Myth16::from_i32_unchecked(100_000), Myth16(-31_072));
+        assert_eq!(Myth16::from(100_000), Myth16(-31_072));
+        assert_eq!(Myth16::from(12_345), Myth16(12_345));
+    }
+
+    #[test]
+    fn round_mm_saturates() {
+        use crate::RoundingMode::HalfUp;
+        assert_eq!(Myth16(32_767).round_mm(1, HalfUp), Myth16::MAX);
+        assert_eq!(Myth16(32_767).round_mm(3, HalfUp), Myth16::MAX);
+        assert_eq!(Myth16::MIN.round_mm(3, HalfUp), Myth16::MIN);
+        assert_eq!(Myth16(32_740).round_mm(2, HalfUp), Myth16(32_700));
+    }
+
     #[test]
     fn min_max() {
         let max = Myth16::MAX;
@@ -3,7 +3,7 @@ use crate::{error::ToleranceError, Myth16, Myth64, Unit};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 use std::str::FromStr;
 
 ///
@@ -39,13 +39,18 @@ use std::str::FromStr;
 ///     assert_eq!(format!("{myth:#}"), "125000");
 /// ```
 ///
-
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+#[repr(transparent)]
 #[must_use]
 pub struct Myth32(pub(crate) i32);
 
 impl Myth32 {
+    /// The biggest value inside the documented range, same as `MAX`.
+    pub const SPEC_MAX: Myth32 = Myth32::MAX;
+    /// The smallest value inside the documented range, same as `MIN`.
+    pub const SPEC_MIN: Myth32 = Myth32::MIN;
+
     #[must_use]
     pub const fn as_i32(&self) -> i32 {
         self.0
@@ -54,8 +59,9 @@ impl Myth32 {
 
 super::standard_myths!(Myth32, i32, u64, u32, u16, u8, usize, i64, i32, i16, i8, isize);
 super::from_number!(Myth32, u16, u8, i32, i16, i8);
-super::try_from_number!(Myth32, u64, u32, i64, isize, usize);
+super::try_from_number!(Myth32, u64, u32, i64, isize, usize, i128);
 super::from_myths!(Myth32, Myth16);
+super::saturating_into_myths!(Myth32, saturating_to_myth16 => Myth16);
 super::try_from_myths!(Myth32, Myth64);
 super::calc_with_myths!(Myth32, i32, Myth32, Myth16);
 #[cfg(feature = "serde")]
@@ -64,6 +70,7 @@ super::de_serde!(Myth32, i32);
 #[cfg(test)]
 mod should {
     use super::{Myth32, Unit};
+    use crate::Myth16;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -130,6 +137,64 @@ mod should {
         assert_eq!(format!("{m:.2}"), "0.00");
     }
 
+    #[test]
+    fn add_sub_f64() {
+        let m = Myth32::from(100.0);
+        assert_eq!(m + 0.5, Myth32::from(100.0 + 0.5));
+        assert_eq!(m - 0.0125, Myth32::from(100.0 - 0.0125));
+        assert_eq!(m - 2.5 + 2.5, m);
+        // integers are added in 0.1μ
+        assert_eq!(m + 5, m + 0.0005);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let m = Myth32::from(1.5);
+        assert_eq!(m.checked_add(m), Some(Myth32::from(3.0)));
+        assert_eq!(Myth32::MAX.checked_add(Myth32(1)), None);
+        assert_eq!(m.checked_sub(Myth32::from(2.0)), Some(Myth32::from(-0.5)));
+        assert_eq!(Myth32::MIN.checked_sub(Myth32(1)), None);
+        assert_eq!(m.checked_mul(-2), Some(Myth32::from(-3.0)));
+        assert_eq!(Myth32::MAX.checked_mul(2), None);
+        assert_eq!(m.checked_mul(i64::MAX), None);
+        assert_eq!(Myth32::ZERO.checked_mul(i64::MAX), Some(Myth32::ZERO));
+        const SUM: Option<Myth32> = Myth32::ONE.checked_add(Myth32::ONE);
+        assert_eq!(SUM, Some(Myth32::from(2.0)));
+    }
+
+    #[test]
+    fn divide_ratio() {
+        assert_eq!(Myth32::from(100.0) / Myth32::from(50.0), 2.0);
+        assert_eq!(Myth32::from(-1.0) / Myth32::from(0.5), -2.0);
+        assert!((Myth32::ONE / Myth32::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn remainder() {
+        assert_eq!(Myth32::from(12.345) % Unit::MM, Myth32(3_450));
+        assert_eq!(-Myth32::from(12.345) % Unit::MM, Myth32(-3_450));
+        assert_eq!(Myth32::from(2.0) % Unit::MM, Myth32::ZERO);
+        assert_eq!(Myth32::from(-0.0125) % Unit::potency(2), Myth32(-25));
+        assert_eq!(Myth32::from(2.0) % Unit::CM, Myth32::from(2.0));
+    }
+
+    #[test]
+    fn saturating_narrowing() {
+        assert_eq!(Myth32::from(2.5).saturating_to_myth16(), Myth16::from(2.5));
+        assert_eq!(Myth32::from(-4.0).saturating_to_myth16(), Myth16::MIN);
+    }
+
+    #[test]
+    fn format_hex() {
+        let m = Myth32::from(1.0);
+        assert_eq!(format!("{m:x}"), "2710");
+        assert_eq!(format!("{m:#x}"), "0x2710");
+        assert_eq!(format!("{m:#010x}"), "0x00002710");
+        assert_eq!(format!("{:X}", Myth32::from(6.5)), "FDE8");
+        assert_eq!(format!("{:x}", Myth32(-1)), "ffffffff");
+        assert_eq!(format!("{:x}", Myth16(-1)), "ffff");
+    }
+
     #[test]
     fn min_max() {
         let max = Myth32::MAX;
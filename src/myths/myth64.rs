@@ -3,7 +3,7 @@ use crate::{error::ToleranceError, Myth16, Myth32, Unit};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 use std::str::FromStr;
 
 ///
@@ -33,20 +33,92 @@ use std::str::FromStr;
 ///
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+#[repr(transparent)]
 #[must_use]
 pub struct Myth64(pub(crate) i64);
 
+/// The SI prefixes of `from_engineering_str`, without a prefix meter is used.
+const ENGINEERING_PREFIXES: [(&str, Unit); 6] = [
+    ("u", Unit::MY),
+    ("\u{b5}", Unit::MY),
+    ("\u{3bc}", Unit::MY),
+    ("m", Unit::MM),
+    ("c", Unit::CM),
+    ("k", Unit::KM),
+];
+
+/// The leading symbols of a dimension: diameter `⌀`, `Ø` and radius `R`.
+const DIMENSION_PREFIXES: [char; 3] = ['\u{2300}', '\u{d8}', 'R'];
+
+impl Myth64 {
+    /// The biggest value inside the documented range, same as `MAX`.
+    pub const SPEC_MAX: Myth64 = Myth64::MAX;
+    /// The smallest value inside the documented range, same as `MIN`.
+    pub const SPEC_MIN: Myth64 = Myth64::MIN;
+
+    /// Parses a length in engineering notation, a number followed by an SI prefix of meter.
+    ///
+    /// Known prefixes are `u` or `µ` (μm), `m` (mm), `c` (cm) and `k` (km), a number without
+    /// a prefix is read in meter.
+    ///
+    /// ```rust
+    /// # use tolerance::Myth64;
+    /// assert_eq!(Myth64::from_engineering_str("500u"), Ok(Myth64::from(0.5)));
+    /// assert_eq!(Myth64::from_engineering_str("2.5m"), Ok(Myth64::from(2.5)));
+    /// assert_eq!(Myth64::from_engineering_str("1.2"), Ok(Myth64::from(1200.0)));
+    /// assert!(Myth64::from_engineering_str("3G").is_err());
+    /// ```
+    pub fn from_engineering_str(value: &str) -> Result<Myth64, ToleranceError> {
+        let value = value.trim();
+        let number = value.trim_end_matches(char::is_alphabetic);
+        let prefix = &value[number.len()..];
+        let unit = if prefix.is_empty() {
+            Unit::METER
+        } else {
+            ENGINEERING_PREFIXES
+                .iter()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, unit)| *unit)
+                .ok_or_else(|| {
+                    ToleranceError::ParseError(format!("Unknown prefix '{prefix}' in '{value}'!"))
+                })?
+        };
+        crate::try_from_str_in(number, unit, "Myth64").map(Myth64)
+    }
+
+    /// Parses a length in mm with an optional leading dimension symbol, the diameter
+    /// `⌀`/`Ø` or the radius `R`, and returns the length and the symbol.
+    ///
+    /// ```rust
+    /// # use tolerance::Myth64;
+    /// assert_eq!(Myth64::from_prefixed_str("\u{2300}25.4"), Ok((Myth64::from(25.4), Some('\u{2300}'))));
+    /// assert_eq!(Myth64::from_prefixed_str("R5.0"), Ok((Myth64::from(5.0), Some('R'))));
+    /// assert_eq!(Myth64::from_prefixed_str("5.0"), Ok((Myth64::from(5.0), None)));
+    /// ```
+    pub fn from_prefixed_str(value: &str) -> Result<(Myth64, Option<char>), ToleranceError> {
+        let value = value.trim();
+        let prefix = value
+            .chars()
+            .next()
+            .filter(|c| DIMENSION_PREFIXES.contains(c));
+        let number = prefix.map_or(value, |p| &value[p.len_utf8()..]);
+        crate::try_from_str(number.trim_start(), "Myth64").map(|v| (Myth64(v), prefix))
+    }
+}
+
 super::calc_with_myths!(Myth64, i64, Myth64, Myth32, Myth16);
 super::from_myths!(Myth64, Myth32, Myth16);
+super::saturating_into_myths!(Myth64, saturating_to_myth32 => Myth32, saturating_to_myth16 => Myth16);
 super::from_number!(Myth64, u32, u16, u8, i64, i32, i16, i8);
 super::standard_myths!(Myth64, i64, u64, u32, u16, u8, usize, i64, i32, i16, i8, isize);
-super::try_from_number!(Myth64, u64, usize, isize);
+super::try_from_number!(Myth64, u64, usize, isize, i128);
 #[cfg(feature = "serde")]
 super::de_serde!(Myth64, i64);
 
 #[cfg(test)]
 mod should {
     use super::{Myth64, Unit};
+    use crate::{error::ToleranceError, Myth16, Myth32};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -154,6 +226,91 @@ mod should {
         assert_eq!(Myth64(-100), m.floor(Unit::potency(2)));
     }
 
+    #[test]
+    fn ceil() {
+        assert_eq!(Myth64(0), Myth64(-67).ceil(Unit::potency(3)));
+        assert_eq!(Myth64(1_000), Myth64(67).ceil(Unit::potency(3)));
+        assert_eq!(Myth64(20_000), Myth64::from(1.0001).ceil(Unit::MM));
+        assert_eq!(Myth64(-10_000), Myth64::from(-1.9999).ceil(Unit::MM));
+        // already on a boundary
+        assert_eq!(Myth64(30_000), Myth64(30_000).ceil(Unit::MM));
+        assert_eq!(Myth64(-30_000), Myth64(-30_000).ceil(Unit::MM));
+        assert_eq!(Myth64(-30_000), Myth64(-30_000).floor(Unit::MM));
+        assert_eq!(Myth64::ZERO, Myth64::ZERO.ceil(Unit::INCH));
+    }
+
+    #[test]
+    fn snap_with_error() {
+        for m in [Myth64(1_234_567), Myth64(-4_993), Myth64(5_000), Myth64(0)] {
+            for unit in [Unit::MY, Unit::MM, Unit::CM, Unit::INCH] {
+                let (rounded, error) = m.snap_with_error(unit);
+                assert_eq!(rounded, m.round(unit));
+                assert_eq!(rounded + error, m);
+            }
+        }
+        let (rounded, error) = Myth64(-4_993).snap_with_error(Unit::MY);
+        assert_eq!((rounded, error), (Myth64(-4_990), Myth64(-3)));
+    }
+
+    #[test]
+    fn clamp_one_sided() {
+        assert_eq!(Myth64(4_500), Myth64(4_500).clamp_positive());
+        assert_eq!(Myth64::ZERO, Myth64(-4_500).clamp_positive());
+        assert_eq!(Myth64::ZERO, Myth64::ZERO.clamp_positive());
+        assert_eq!(Myth64::ZERO, Myth64(4_500).clamp_negative());
+        assert_eq!(Myth64(-4_500), Myth64(-4_500).clamp_negative());
+        assert_eq!(Myth64::ZERO, Myth64::ZERO.clamp_negative());
+    }
+
+    #[test]
+    fn step_for_precision() {
+        assert_eq!(Myth64::step_for_precision(0), Myth64::ONE);
+        assert_eq!(Myth64::step_for_precision(1), Myth64::from(0.1));
+        assert_eq!(Myth64::step_for_precision(2), Myth64(100));
+        assert_eq!(Myth64::step_for_precision(3), Myth64(10));
+        assert_eq!(Myth64::step_for_precision(4), Myth64(1));
+        assert_eq!(Myth64::step_for_precision(7), Myth64(1));
+        assert_eq!(
+            format!("{:.2}", Myth64::from(1.25) + Myth64::step_for_precision(2)),
+            "1.26"
+        );
+    }
+
+    #[test]
+    fn round_mm() {
+        use crate::RoundingMode::*;
+        assert_eq!(
+            Myth64::from(1.24),
+            Myth64::from(1.235).round_mm(2, HalfEven)
+        );
+        assert_eq!(
+            Myth64::from(1.24),
+            Myth64::from(1.245).round_mm(2, HalfEven)
+        );
+        assert_eq!(Myth64::from(1.24), Myth64::from(1.235).round_mm(2, HalfUp));
+        assert_eq!(Myth64::from(1.25), Myth64::from(1.245).round_mm(2, HalfUp));
+        assert_eq!(
+            Myth64::from(1.24),
+            Myth64::from(1.245).round_mm(2, HalfDown)
+        );
+        assert_eq!(
+            Myth64::from(-1.25),
+            Myth64::from(-1.245).round_mm(2, HalfUp)
+        );
+        assert_eq!(
+            Myth64::from(-1.24),
+            Myth64::from(-1.245).round_mm(2, HalfEven)
+        );
+        assert_eq!(
+            Myth64::from(1.25),
+            Myth64::from(1.2451).round_mm(2, HalfEven)
+        );
+        assert_eq!(Myth64::from(2.0), Myth64::from(2.5).round_mm(0, HalfEven));
+        assert_eq!(Myth64(12_345), Myth64(12_345).round_mm(7, HalfEven));
+        assert_eq!(Myth64::MAX, Myth64::MAX.round_mm(0, HalfUp));
+        assert_eq!(Myth64::MIN, Myth64::MIN.round_mm(0, HalfUp));
+    }
+
     #[test]
     fn display() {
         let m = Myth64(12455);
@@ -176,6 +333,427 @@ mod should {
         assert_eq!(format!("{m:.2}"), "0.00");
     }
 
+    #[test]
+    fn to_mm4() {
+        assert_eq!(Myth64::from(12.0).to_mm4(), "12.0000");
+        assert_eq!(Myth64::from(1.2).to_mm4(), "1.2000");
+        assert_eq!(Myth64::from(-0.0345).to_mm4(), "-0.0345");
+        assert_eq!(Myth64::ZERO.to_mm4(), "0.0000");
+    }
+
+    #[test]
+    fn from_engineering_str() {
+        assert_eq!(Myth64::from_engineering_str("500u"), Ok(Myth64::from(0.5)));
+        assert_eq!(
+            Myth64::from_engineering_str("-12.5\u{b5}"),
+            Ok(Myth64(-125))
+        );
+        assert_eq!(Myth64::from_engineering_str("2.5m"), Ok(Myth64::from(2.5)));
+        assert_eq!(Myth64::from_engineering_str("1.5c"), Ok(Myth64::from(15.0)));
+        assert_eq!(
+            Myth64::from_engineering_str("1.2k"),
+            Ok(Myth64::from(1_200_000.0))
+        );
+        assert_eq!(
+            Myth64::from_engineering_str(" 0.75 "),
+            Ok(Myth64::from(750.0))
+        );
+        assert!(Myth64::from_engineering_str("3M").is_err());
+        assert!(Myth64::from_engineering_str("3mm").is_err());
+        assert!(Myth64::from_engineering_str("u").is_err());
+    }
+
+    #[test]
+    fn natural_unit() {
+        assert_eq!(Myth64::from(2000.0).natural_unit(), Unit::METER);
+        assert_eq!(Myth64::from(-3_000_000.0).natural_unit(), Unit::KM);
+        assert_eq!(Myth64::from(120.0).natural_unit(), Unit::CM);
+        assert_eq!(Myth64::from(2.0).natural_unit(), Unit::MM);
+        // 2500 μ
+        assert_eq!(Myth64::from(2.5).natural_unit(), Unit::MY);
+        assert_eq!(Myth64::from(2.001).natural_unit(), Unit::MY);
+        assert_eq!(Myth64(12_345).natural_unit(), Unit::potency(0));
+        assert_eq!(Myth64::ZERO.natural_unit(), Unit::KM);
+    }
+
+    #[test]
+    fn kind() {
+        use crate::{Myth16, Myth32};
+        assert_eq!(Myth64::ZERO.kind(), "Myth64");
+        assert_eq!(Myth32::ZERO.kind(), "Myth32");
+        assert_eq!(Myth16::ZERO.kind(), "Myth16");
+        assert_eq!(Myth64::ONE.byte_len(), 8);
+        assert_eq!(Myth32::ONE.byte_len(), 4);
+        assert_eq!(Myth16::ONE.byte_len(), 2);
+        assert_eq!(Myth64::ONE.to_be_bytes().len(), Myth64::ONE.byte_len());
+    }
+
+    #[test]
+    fn checked_powi() {
+        use crate::Myth32;
+        let m = Myth64::from(-0.5);
+        assert_eq!(m.checked_powi(0), Some(Myth64::ONE));
+        assert_eq!(m.checked_powi(1), Some(m));
+        assert_eq!(m.checked_powi(3), Some(Myth64::from(-0.125)));
+        assert_eq!(Myth64(15).checked_powi(2), Some(Myth64(0)));
+        // 500 mm squared overflows a Myth32, but not a Myth64
+        assert_eq!(Myth32::from(500.0).checked_powi(2), None);
+        assert_eq!(
+            Myth64::from(500.0).checked_powi(2),
+            Some(Myth64::from(250_000.0))
+        );
+        assert_eq!(Myth64::from(100_000_000.0).checked_powi(2), None);
+        assert_eq!(Myth64::from(1_000_000.0).checked_powi(9), None);
+        assert_eq!(Myth64::ONE.checked_powi(u32::MAX), Some(Myth64::ONE));
+        assert_eq!(
+            Myth64(-10_000).checked_powi(u32::MAX),
+            Some(Myth64(-10_000))
+        );
+        assert_eq!(
+            Myth64(-10_000).checked_powi(u32::MAX - 1),
+            Some(Myth64::ONE)
+        );
+        assert_eq!(Myth64::ZERO.checked_powi(u32::MAX), Some(Myth64::ZERO));
+        assert_eq!(Myth64::ZERO.checked_powi(0), Some(Myth64::ONE));
+        assert_eq!(Myth64(9_999).checked_powi(u32::MAX), Some(Myth64::ZERO));
+        assert_eq!(Myth32(10_001).checked_powi(u32::MAX), None);
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(
+            Myth64::midpoint(Myth64::from(1.0), Myth64::from(2.0)),
+            Myth64::from(1.5)
+        );
+        assert_eq!(Myth64::midpoint(Myth64(-3), Myth64(0)), Myth64(-1));
+        assert_eq!(Myth64::midpoint(Myth64(3), Myth64(0)), Myth64(1));
+        assert_eq!(Myth64::midpoint(Myth64::MAX, Myth64::MAX), Myth64::MAX);
+        assert_eq!(Myth64::midpoint(Myth64::MIN, Myth64::MIN), Myth64::MIN);
+        assert_eq!(
+            Myth64::midpoint(Myth64::MAX, Myth64(i64::MAX - 2)),
+            Myth64(i64::MAX - 1)
+        );
+        assert_eq!(Myth64::midpoint(Myth64::MIN, Myth64::MAX), Myth64::ZERO);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn convert_f16() {
+        use crate::Myth16;
+        use half::f16;
+        for mm in [0.0, 0.5, -1.25, 12.3456, 2048.4] {
+            let m = Myth64::from(mm);
+            let back = Myth64::saturating_from_f16(m.as_f16());
+            // the spacing of f16 is 2^-10 relative to the value
+            assert!(back.abs_diff(m).as_f64() <= f64::abs(mm) / 1024.0);
+        }
+        assert_eq!(Myth64::from(0.5).as_f16(), f16::from_f32(0.5));
+        assert_eq!(
+            Myth64::saturating_from_f16(f16::from_f32(0.25)),
+            Myth64::from(0.25)
+        );
+        assert_eq!(Myth16::saturating_from_f16(f16::MAX), Myth16::MAX);
+        assert_eq!(Myth16::saturating_from_f16(f16::NEG_INFINITY), Myth16::MIN);
+        assert_eq!(Myth64::saturating_from_f16(f16::NAN), Myth64::ZERO);
+    }
+
+    #[test]
+    fn add_sub_f64() {
+        let m = Myth64::from(1000.0);
+        assert_eq!(m + 0.5, Myth64::from(1000.0 + 0.5));
+        assert_eq!(m - 0.0125, Myth64::from(1000.0 - 0.0125));
+        assert_eq!(m - 2.5 + 2.5, m);
+        // integers are added in 0.1μ
+        assert_eq!(m + 5, m + 0.0005);
+    }
+
+    #[test]
+    fn write_mm() {
+        let mut out = String::with_capacity(64);
+        for (m, decimals) in [
+            (Myth64::from(12.5), 2),
+            (Myth64(-455), 3),
+            (Myth64(12_455), 0),
+            (Myth64(-5_000), 0),
+            (Myth64::ZERO, 4),
+            (Myth64(-3), 9),
+        ] {
+            m.write_mm(&mut out, decimals).unwrap();
+            assert!(out.ends_with(&format!("{m:.decimals$}")));
+            out.push(';');
+        }
+        assert_eq!(out, "12.50;-0.046;1;-1;0.0000;-0.0003;");
+    }
+
+    #[test]
+    fn radix_string() {
+        assert_eq!(Myth64(255).to_radix_string(16), "ff");
+        assert_eq!(Myth64(-255).to_radix_string(2), "-11111111");
+        assert_eq!(Myth64::ZERO.to_radix_string(36), "0");
+        for m in [Myth64(1_234_567), Myth64(-42), Myth64::MAX, Myth64::MIN] {
+            for radix in [16, 36] {
+                let s = m.to_radix_string(radix);
+                assert_eq!(Myth64::from_radix_str(&s, radix), Ok(m));
+            }
+        }
+        assert_eq!(
+            Myth64::MIN.to_radix_string(36),
+            format!("-{}", "1y2p0ij32e8e8")
+        );
+        assert!(Myth64::from_radix_str("zz", 16).is_err());
+        assert!(Myth64::from_radix_str("10", 37).is_err());
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(Myth64::MAX.saturating_add(Myth64::ONE), Myth64::MAX);
+        assert_eq!(Myth64::MIN.saturating_sub(Myth64::ONE), Myth64::MIN);
+        assert_eq!(Myth64::ONE.saturating_add(Myth64::ONE), Myth64::from(2.0));
+    }
+
+    #[test]
+    fn divide_ratio() {
+        assert_eq!(Myth64::from(100.0) / Myth64::from(50.0), 2.0);
+        assert_eq!(Myth64::from(-1.0) / Myth64::from(0.5), -2.0);
+        assert!((Myth64::ONE / Myth64::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn remainder() {
+        assert_eq!(Myth64::from(12.345) % Unit::MM, Myth64(3_450));
+        assert_eq!(-Myth64::from(12.345) % Unit::MM, Myth64(-3_450));
+        assert_eq!(Myth64::from(2.0) % Unit::MM, Myth64::ZERO);
+        assert_eq!(Myth64::from(-0.0125) % Unit::potency(2), Myth64(-25));
+        assert_eq!(Myth64::from(2.0) % Unit::CM, Myth64::from(2.0));
+    }
+
+    #[test]
+    fn from_unit_value() {
+        use crate::{Myth16, Myth32};
+        assert_eq!(
+            Myth64::from_unit_value(5, Unit::INCH),
+            Ok(Myth64::from(127.0))
+        );
+        assert_eq!(
+            Myth64::from_unit_value(-3, Unit::METER),
+            Ok(Myth64::from(-3000.0))
+        );
+        assert_eq!(
+            Myth32::from_unit_value(5, Unit::INCH),
+            Ok(Myth32::from(127.0))
+        );
+        assert!(Myth16::from_unit_value(5, Unit::INCH).is_err());
+        assert!(Myth32::from_unit_value(1, Unit::MILE).is_err());
+        assert!(Myth64::from_unit_value(i64::MAX, Unit::MY).is_err());
+    }
+
+    #[test]
+    fn clamp() {
+        let (min, max) = (Myth64::from(-1.0), Myth64::from(2.5));
+        assert_eq!(Myth64::from(-1.5).clamp(min, max), min);
+        assert_eq!(Myth64::from(3.0).clamp(min, max), max);
+        assert_eq!(Myth64::from(0.5).clamp(min, max), Myth64::from(0.5));
+        assert_eq!(max.clamp(min, max), max);
+        const CLAMPED: Myth64 = Myth64::MAX.clamp(Myth64::ZERO, Myth64::ONE);
+        assert_eq!(CLAMPED, Myth64::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_invalid_range() {
+        let _ = Myth64::ZERO.clamp(Myth64::ONE, Myth64::ZERO);
+    }
+
+    #[test]
+    fn div_rem() {
+        let step = Unit::MM * 5;
+        assert_eq!(Myth64::from(13.0).div_rem(step), (2, Myth64::from(3.0)));
+        assert_eq!(Myth64::from(10.0).div_rem(step), (2, Myth64::ZERO));
+        assert_eq!(Myth64::from(-13.0).div_rem(step), (-2, Myth64::from(-3.0)));
+        let (q, r) = Myth64::from(13.0).div_rem(step);
+        assert_eq!(Myth64::from(13.0) % step, r);
+        assert_eq!(Myth64::from(step * q) + r, Myth64::from(13.0));
+    }
+
+    #[test]
+    fn divides_evenly() {
+        assert!(Myth64::from(10.0).divides_evenly(4));
+        assert!(!Myth64::from(1.0).divides_evenly(3));
+        assert!(Myth64::from(-1.2).divides_evenly(-3));
+        assert!(Myth64::ZERO.divides_evenly(7));
+        assert!(Myth64::MIN.divides_evenly(-1));
+        assert!(!Myth64::MIN.divides_evenly(i64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn divides_evenly_by_zero() {
+        let _ = Myth64::ONE.divides_evenly(0);
+    }
+
+    #[test]
+    fn saturating_narrowing() {
+        let v = Myth64::from(-1.5);
+        assert_eq!(v.saturating_to_myth32(), Myth32::from(-1.5));
+        assert_eq!(v.saturating_to_myth16(), Myth16::from(-1.5));
+        assert_eq!(
+            Myth64::from(1_000.0).saturating_to_myth32(),
+            Myth32::from(1_000.0)
+        );
+        assert_eq!(Myth64::from(1_000.0).saturating_to_myth16(), Myth16::MAX);
+        assert_eq!(Myth64::MIN.saturating_to_myth32(), Myth32::MIN);
+        assert_eq!(Myth64::MAX.saturating_to_myth32(), Myth32::MAX);
+    }
+
+    #[test]
+    fn round_toward() {
+        let tie = Myth64::from(2.5);
+        assert_eq!(
+            tie.round_toward(Unit::MM, Myth64::from(3.0)),
+            Myth64::from(3.0)
+        );
+        assert_eq!(
+            tie.round_toward(Unit::MM, Myth64::from(2.0)),
+            Myth64::from(2.0)
+        );
+        assert_eq!(tie.round_toward(Unit::MM, tie), tie.round(Unit::MM));
+        let neg = Myth64::from(-2.5);
+        assert_eq!(neg.round_toward(Unit::MM, Myth64::ZERO), Myth64::from(-2.0));
+        assert_eq!(
+            neg.round_toward(Unit::MM, Myth64::from(-10.0)),
+            Myth64::from(-3.0)
+        );
+        // no tie, the reference doesn't matter
+        let m = Myth64::from(2.4);
+        assert_eq!(
+            m.round_toward(Unit::MM, Myth64::from(100.0)),
+            Myth64::from(2.0)
+        );
+        assert_eq!(
+            Myth64::from(2.0).round_toward(Unit::MM, Myth64::ZERO),
+            Myth64::from(2.0)
+        );
+    }
+
+    #[test]
+    fn count_of() {
+        let m = Myth64::from(1.2345);
+        assert_eq!(m.count_of(Unit::MY), 1234.5);
+        assert_eq!(m.count_of(Unit::MM), 1.2345);
+        assert_eq!(Myth64::from(-2.5).count_of(Unit::MM), -2.5);
+        assert_eq!(m.count_of(Unit::INCH), m.as_unit(Unit::INCH));
+    }
+
+    #[test]
+    fn from_prefixed_str() {
+        assert_eq!(
+            Myth64::from_prefixed_str("\u{2300}25.4"),
+            Ok((Myth64::from(25.4), Some('\u{2300}')))
+        );
+        assert_eq!(
+            Myth64::from_prefixed_str("\u{d8} 12"),
+            Ok((Myth64::from(12.0), Some('\u{d8}')))
+        );
+        assert_eq!(
+            Myth64::from_prefixed_str("R5.0"),
+            Ok((Myth64::from(5.0), Some('R')))
+        );
+        assert_eq!(
+            Myth64::from_prefixed_str("-0.5"),
+            Ok((Myth64::from(-0.5), None))
+        );
+        assert!(Myth64::from_prefixed_str("D5.0").is_err());
+        assert!(Myth64::from_prefixed_str("R").is_err());
+    }
+
+    #[test]
+    fn parse_unit_suffix() {
+        let mm = |v: f64| Ok(Myth64::from(v));
+        assert_eq!("25.4".parse(), mm(25.4));
+        assert_eq!("25.4mm".parse(), mm(25.4));
+        assert_eq!("0.5cm".parse(), mm(5.0));
+        assert_eq!("1.5m".parse(), mm(1_500.0));
+        assert_eq!("1in".parse(), mm(25.4));
+        assert_eq!("1ft".parse(), mm(304.8));
+        assert_eq!("1yd".parse(), mm(914.4));
+        assert_eq!("12um".parse(), mm(0.012));
+        assert_eq!("12\u{b5}".parse(), mm(0.012));
+        assert_eq!("2thou".parse(), mm(0.0508));
+        assert_eq!(" -3 mm ".parse(), mm(-3.0));
+        assert!(matches!(
+            "3parsec".parse::<Myth64>(),
+            Err(ToleranceError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn format_in() {
+        assert_eq!(Myth64::from(25.4).format_in(Unit::INCH), "1 in");
+        assert_eq!(Myth64::from(12.5).format_in(Unit::MM), "12.5 mm");
+        assert_eq!(Myth64::from(-0.0001).format_in(Unit::MM), "-0.0001 mm");
+        assert_eq!(Myth64::from(1_500.0).format_in(Unit::METER), "1.5 m");
+        assert_eq!(Myth64::from(20.0).format_in(Unit::potency(2)), "2000");
+        assert_eq!(Myth64::from(25.4).format_in_prec(Unit::INCH, 3), "1.000 in");
+        assert_eq!(
+            Myth64::from(1_234.5).format_in_prec(Unit::METER, 2),
+            "1.23 m"
+        );
+        assert_eq!(Myth64::from(12.5).format_in_prec(Unit::MM, 0), "12 mm");
+    }
+
+    #[test]
+    fn nm_and_dm() {
+        assert_eq!(Myth64::from(0.0001).as_nm(), 100.0);
+        assert_eq!(Myth64::from(-1.5).as_nm(), -1_500_000.0);
+        assert_eq!(Myth64::from(250.0).as_unit(Unit::DM), 2.5);
+        assert_eq!("2.5dm".parse(), Ok(Myth64::from(250.0)));
+    }
+
+    #[test]
+    fn steps_between() {
+        let (a, b) = (Myth64::from(-1.0), Myth64::from(2.0));
+        assert_eq!(Myth64::steps_between(a, b, Myth64::from(0.5)), Some(6));
+        assert_eq!(Myth64::steps_between(b, a, Myth64::from(0.5)), Some(6));
+        assert_eq!(Myth64::steps_between(a, b, Myth64::from(-0.5)), Some(6));
+        assert_eq!(Myth64::steps_between(a, a, Myth64::from(0.5)), Some(0));
+        assert_eq!(Myth64::steps_between(a, b, Myth64::from(0.7)), None);
+        assert_eq!(Myth64::steps_between(a, b, Myth64::ZERO), None);
+        assert_eq!(
+            Myth64::steps_between(Myth64::MIN, Myth64::MAX, Myth64(1)),
+            Some(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn format_exp() {
+        let m = Myth64::from(1_234_000.0);
+        assert_eq!(format!("{m:.3e}"), "1.234e6");
+        assert_eq!(format!("{m:E}"), "1.234E6");
+        assert_eq!(format!("{:e}", Myth64::from(-0.0025)), "-2.5e-3");
+        assert_eq!(format!("{:.1e}", Myth64::from(42.0)), "4.2e1");
+        assert_eq!(format!("{:e}", Myth64::ZERO), "0e0");
+    }
+
+    #[test]
+    fn parse_exponent() {
+        let mm = |v: f64| Ok(Myth64::from(v));
+        assert_eq!("1.5e1".parse(), mm(15.0));
+        assert_eq!("2.5e-1".parse(), mm(0.25));
+        assert_eq!("-2.5E-1".parse(), mm(-0.25));
+        assert_eq!("1.23456e1".parse(), Ok(Myth64(123_456)));
+        assert_eq!("12e+2".parse(), mm(1_200.0));
+        assert_eq!("0.5e0".parse(), mm(0.5));
+        assert_eq!("1e-40".parse(), mm(0.0));
+        assert_eq!("1.5e-3in".parse(), Ok(Myth64(381)));
+        assert!("1e".parse::<Myth64>().is_err());
+        assert!("1e1.5".parse::<Myth64>().is_err());
+        assert!("e5".parse::<Myth64>().is_err());
+        assert!(matches!(
+            "1e40".parse::<Myth64>(),
+            Err(ToleranceError::Overflow(_))
+        ));
+    }
+
     #[test]
     fn min_max() {
         let max = Myth64::MAX;
@@ -184,6 +762,17 @@ mod should {
         assert_eq!(min.0, -9_223_372_036_854_775_808);
     }
 
+    #[test]
+    fn checked_from_bytes() {
+        let m = Myth64::from(12456.832);
+        assert_eq!(Ok(m), Myth64::checked_from_be_bytes(&m.to_be_bytes()));
+        assert_eq!(
+            Ok(Myth64::MIN),
+            Myth64::checked_from_le_bytes(&Myth64::MIN.to_le_bytes())
+        );
+        assert!(Myth64::checked_from_be_bytes(&m.to_be_bytes()[1..]).is_err());
+    }
+
     #[test]
     fn as_unit() {
         let m = Myth64::from(12456.832);
@@ -193,6 +782,54 @@ mod should {
         assert_eq!(m.as_unit(Unit::KM), 922_337_203.685_477_6);
     }
 
+    #[test]
+    fn abs_diff_unit() {
+        let a = Myth64::from(30.0);
+        let b = Myth64::from(4.6);
+        assert_eq!(25.4, a.abs_diff_unit(b, Unit::MM));
+        assert_eq!(1.0, b.abs_diff_unit(a, Unit::INCH));
+        assert_eq!(
+            u64::MAX as f64 / 10.0,
+            Myth64::MAX.abs_diff_unit(Myth64::MIN, Unit::MY)
+        );
+    }
+
+    #[test]
+    fn convert_i128() {
+        assert_eq!(Ok(Myth64(-123_456)), Myth64::try_from(-123_456i128));
+        assert_eq!(Ok(Myth64::MAX), Myth64::try_from(i128::from(i64::MAX)));
+        assert!(matches!(
+            Myth64::try_from(i128::from(i64::MAX) + 1),
+            Err(ToleranceError::Overflow(_))
+        ));
+        assert_eq!(-123_456i128, i128::from(Myth64(-123_456)));
+    }
+
+    #[test]
+    fn cumulative_sum() {
+        let segments = [
+            Myth64::from(10.0),
+            Myth64::from(2.5),
+            Myth64::from(-0.5),
+            Myth64::from(7.25),
+        ];
+        assert_eq!(
+            Ok(vec![
+                Myth64::from(10.0),
+                Myth64::from(12.5),
+                Myth64::from(12.0),
+                Myth64::from(19.25)
+            ]),
+            Myth64::cumulative_sum(&segments)
+        );
+        assert_eq!(Ok(vec![]), Myth64::cumulative_sum(&[]));
+        assert!(Myth64::cumulative_sum(&[Myth64::MAX, Myth64::ONE]).is_err());
+        assert_eq!(
+            Ok(vec![Myth64::MAX, Myth64::MAX - Myth64::ONE]),
+            Myth64::cumulative_sum(&[Myth64::MAX, -Myth64::ONE])
+        );
+    }
+
     #[test]
     fn sum() {
         let m64s = (0..10).map(|d| Myth64::from(d * 10_000));
@@ -203,13 +840,15 @@ mod should {
     mod serde {
         use crate::Myth64;
         use serde::{Deserialize, Serialize};
-        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token,
+        };
 
         #[test]
         fn serialize_i64() {
             let m = Myth64::from(12456.832);
             assert_tokens(
-                &m,
+                &m.readable(),
                 &[
                     Token::NewtypeStruct { name: "Myth64" },
                     Token::I64(124_568_320),
@@ -219,25 +858,25 @@ mod should {
 
         #[test]
         fn deserialize_string() {
-            assert_de_tokens_error::<Myth64>(
+            assert_de_tokens_error::<Readable<Myth64>>(
                 &[Token::String("nonumber")],
                 "invalid value: string \"nonumber\", expected 1.0",
             );
-            assert_de_tokens(&Myth64::from(23.004), &[Token::String("23.004")]);
-            assert_de_tokens(&Myth64::from(0.04), &[Token::String(".04")]);
-            assert_de_tokens(&Myth64::from(0.04), &[Token::Str(".04")]);
+            assert_de_tokens(&Myth64::from(23.004).readable(), &[Token::String("23.004")]);
+            assert_de_tokens(&Myth64::from(0.04).readable(), &[Token::String(".04")]);
+            assert_de_tokens(&Myth64::from(0.04).readable(), &[Token::Str(".04")]);
         }
 
         #[test]
         fn deserialize_f64() {
-            assert_de_tokens(&Myth64::from(23.004), &[Token::F64(23.004)]);
-            assert_de_tokens(&Myth64::from(0.0043), &[Token::F64(0.0043)]);
+            assert_de_tokens(&Myth64::from(23.004).readable(), &[Token::F64(23.004)]);
+            assert_de_tokens(&Myth64::from(0.0043).readable(), &[Token::F64(0.0043)]);
         }
 
         #[test]
         fn deserialize_i64() {
-            assert_de_tokens(&Myth64::from(23.004), &[Token::I64(23_0040)]);
-            assert_de_tokens(&Myth64::from(0.0043), &[Token::I64(0_0043)]);
+            assert_de_tokens(&Myth64::from(23.004).readable(), &[Token::I64(23_0040)]);
+            assert_de_tokens(&Myth64::from(0.0043).readable(), &[Token::I64(43)]);
         }
 
         #[test]
@@ -254,7 +893,7 @@ mod should {
                 serde_json::to_string_pretty(&t1).unwrap()
             );
             assert_de_tokens(
-                &t1,
+                &t1.readable(),
                 &[
                     Token::Struct { name: "T", len: 1 },
                     Token::Str("w"),
@@ -265,7 +904,7 @@ mod should {
             );
             let t2 = T { w: None };
             assert_de_tokens(
-                &t2,
+                &t2.readable(),
                 &[
                     Token::Struct { name: "T", len: 1 },
                     Token::Str("w"),
@@ -274,16 +913,16 @@ mod should {
                 ],
             );
             assert_de_tokens(
-                &Some(Myth64::from(0.0043)),
+                &Some(Myth64::from(0.0043)).readable(),
                 &[Token::Some, Token::String(".0043")],
             );
-            assert_de_tokens(&None::<Myth64>, &[Token::None]);
+            assert_de_tokens(&None::<Myth64>.readable(), &[Token::None]);
         }
 
         #[test]
         fn deserialize_i32() {
-            assert_de_tokens(&Myth64::from(23.004), &[Token::I32(23_0040)]);
-            assert_de_tokens(&Myth64::from(0.0043), &[Token::I32(0_0043)]);
+            assert_de_tokens(&Myth64::from(23.004).readable(), &[Token::I32(23_0040)]);
+            assert_de_tokens(&Myth64::from(0.0043).readable(), &[Token::I32(43)]);
         }
 
         #[test]
@@ -307,6 +946,23 @@ mod should {
             assert_eq!(Some(Myth64::from(0.4)), m);
         }
 
+        #[test]
+        fn serialize_as_int_or_float() {
+            #[derive(Serialize)]
+            struct T1 {
+                #[serde(serialize_with = "Myth64::as_int_or_float")]
+                width: Myth64,
+            }
+            let t = T1 {
+                width: Myth64::from(100.0),
+            };
+            assert_eq!(r#"{"width":100}"#, serde_json::to_string(&t).unwrap());
+            let t = T1 {
+                width: Myth64::from(-100.5),
+            };
+            assert_eq!(r#"{"width":-100.5}"#, serde_json::to_string(&t).unwrap());
+        }
+
         #[test]
         fn serialize_to_myth_string() {
             use crate::into_string;
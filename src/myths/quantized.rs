@@ -0,0 +1,67 @@
+use crate::{Myth64, Unit};
+use std::hash::{Hash, Hasher};
+
+/// # Grid-snapped `Myth64` key
+///
+/// Compares and hashes the value rounded to the grid `Unit`, so near-equal values collide as
+/// keys of a `HashMap` or `HashSet`.
+///
+/// ```rust
+/// # use tolerance::{Myth64, Quantized, Unit};
+/// let grid = Unit::potency(1); // 0.001 mm
+/// assert_eq!(
+///     Quantized(Myth64::from(1.0001), grid),
+///     Quantized(Myth64::from(1.0002), grid)
+/// );
+/// ```
+///
+/// Values rounding to different grid points stay different, even if they are close to each
+/// other, like `1.0004` and `1.0005` at a 0.001 mm grid. Values on different grids are never
+/// equal.
+#[derive(Copy, Clone, Debug)]
+pub struct Quantized(pub Myth64, pub Unit);
+
+impl Quantized {
+    /// Returns the value snapped to the grid.
+    pub fn snapped(&self) -> Myth64 {
+        self.0.round(self.1)
+    }
+}
+
+impl PartialEq for Quantized {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1 && self.snapped() == other.snapped()
+    }
+}
+
+impl Eq for Quantized {}
+
+impl Hash for Quantized {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.snapped().hash(state);
+        (*self.1).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::Quantized;
+    use crate::{Myth64, Unit};
+    use std::collections::HashSet;
+
+    #[test]
+    fn collide_near_equal_values() {
+        let grid = Unit::potency(1);
+        let set: HashSet<Quantized> = [1.0001, 1.0002, 0.9998, 1.0]
+            .into_iter()
+            .map(|mm| Quantized(Myth64::from(mm), grid))
+            .collect();
+        assert_eq!(set.len(), 1);
+
+        let mut set = set;
+        set.insert(Quantized(Myth64::from(1.0008), grid));
+        set.insert(Quantized(Myth64::from(1.0), Unit::MM));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Quantized(Myth64::from(1.001), grid)));
+    }
+}
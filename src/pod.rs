@@ -0,0 +1,53 @@
+//! Zero-copy casting with [`bytemuck`](https://docs.rs/bytemuck), activated by the `bytemuck`-feature.
+//!
+//! The `Myth`-types are `#[repr(transparent)]` over their integer, the tolerance-types are
+//! `#[repr(C)]` with `value`, `plus` and `minus` in this order and without any padding.
+
+use crate::{Myth16, Myth32, Myth64, T128, T64};
+
+macro_rules! impl_pod {
+    ($($Self:ident),+) => {
+        $(
+            // SAFETY: all bit patterns are valid, the all-zero one is `ZERO`.
+            unsafe impl bytemuck::Zeroable for $Self {}
+            // SAFETY: `Copy`, `'static`, a fixed layout without padding holding only integers.
+            unsafe impl bytemuck::Pod for $Self {}
+        )+
+    };
+}
+
+impl_pod!(Myth64, Myth32, Myth16, T128, T64);
+
+// The tolerance-types must not contain any padding.
+const _: () = assert!(std::mem::size_of::<T128>() == 16);
+const _: () = assert!(std::mem::size_of::<T64>() == 8);
+
+#[cfg(test)]
+mod should {
+    use crate::{Myth64, T128, T64};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cast_myths() {
+        let myths = [Myth64::from(1.5), Myth64::from(-0.0002), Myth64::MAX];
+        let bytes: &[u8] = bytemuck::cast_slice(&myths);
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[..8], &15_000i64.to_ne_bytes());
+        let back: &[Myth64] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &myths);
+    }
+
+    #[test]
+    fn cast_tolerances() {
+        let tols = [T128::new(25.0, 0.021, 0.0), T128::with_sym(-3.0, 0.1)];
+        let bytes: &[u8] = bytemuck::cast_slice(&tols);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[8..12], &210i32.to_ne_bytes());
+        let back: &[T128] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &tols);
+
+        let t = T64::new(12.0, 0.1, -0.2);
+        assert_eq!(bytemuck::cast::<[u8; 8], T64>(bytemuck::cast(t)), t);
+        assert_eq!(<T64 as bytemuck::Zeroable>::zeroed(), T64::ZERO);
+    }
+}
@@ -19,11 +19,90 @@ macro_rules! multiply_tolerance {
 
 pub(crate) use multiply_tolerance;
 
+/// Divides `value`, `plus` and `minus` by an integer, each truncated toward zero.
+macro_rules! divide_tolerance {
+    ($Self:ident, $($typ:ty),+) => {
+
+        $(impl Div<$typ> for $Self {
+            type Output = Self;
+            fn div(self, rsh: $typ) -> Self {
+                $Self {
+                    value: self.value / rsh,
+                    plus: self.plus / rsh,
+                    minus: self.minus / rsh,
+                }
+            }
+        })+
+    };
+}
+
+pub(crate) use divide_tolerance;
+
 macro_rules! tolerance_body {
     ($Self:ident, $value:ident, $tol:ident) => {
         const PPOS : usize = std::mem::size_of::<$value>();
         const MPOS : usize = std::mem::size_of::<$value>() + std::mem::size_of::<$tol>();
 
+        /// A field of the tolerance read by name, see [`Fields`].
+        #[derive(Copy, Clone)]
+        enum Field {
+            Value,
+            Plus,
+            Minus,
+        }
+
+        impl Field {
+            /// The accepted names, each followed by its alias.
+            const NAMES: [&'static str; 6] = ["value", "v", "plus", "p", "minus", "m"];
+
+            fn from_name(name: &str) -> Option<Field> {
+                match name {
+                    "value" | "v" => Some(Field::Value),
+                    "plus" | "p" => Some(Field::Plus),
+                    "minus" | "m" => Some(Field::Minus),
+                    _ => None,
+                }
+            }
+
+            const fn name(self) -> &'static str {
+                match self {
+                    Field::Value => "value",
+                    Field::Plus => "plus",
+                    Field::Minus => "minus",
+                }
+            }
+        }
+
+        /// Collects the fields read by name, shared by the deserializer and
+        #[doc = concat!("`", stringify!($Self), "::try_from(&HashMap<String, f64>)`.")]
+        #[derive(Default)]
+        struct Fields {
+            value: Option<$value>,
+            plus: Option<$tol>,
+            minus: Option<$tol>,
+        }
+
+        impl Fields {
+            /// Returns `true`, if the `field` is already set.
+            fn is_set(&self, field: Field) -> bool {
+                match field {
+                    Field::Value => self.value.is_some(),
+                    Field::Plus => self.plus.is_some(),
+                    Field::Minus => self.minus.is_some(),
+                }
+            }
+
+            /// A missing `plus` is zero and a missing `minus` is `-plus`, returns `None` without a `value`.
+            fn build(self) -> Option<$Self> {
+                let plus = self.plus.unwrap_or($tol::ZERO);
+                Some($Self {
+                    value: self.value?,
+                    plus,
+                    minus: self.minus.unwrap_or(-plus),
+                })
+            }
+        }
+
         impl $Self {
             /// The neutral element in relation to addition and subtraction
             pub const ZERO: $Self = $Self {
@@ -59,6 +138,235 @@ macro_rules! tolerance_body {
                 Self::new(value, tol, -tol)
             }
 
+            #[doc = concat!("Creates a `", stringify!($Self), "` with the unilateral tolerance `+plus/-0`.")]
+            ///
+            /// ### Panics
+            /// If `plus` is negative.
+            pub fn upper_only(value: impl Into<$value>, plus: impl Into<$tol>) -> Self {
+                Self::new(value, plus, $tol::ZERO)
+            }
+
+            #[doc = concat!("Creates a `", stringify!($Self), "` with the unilateral tolerance `+0/minus`.")]
+            ///
+            /// ### Panics
+            /// If `minus` is positive.
+            pub fn lower_only(value: impl Into<$value>, minus: impl Into<$tol>) -> Self {
+                Self::new(value, $tol::ZERO, minus)
+            }
+
+            /// Returns `true`, if one of `plus` and `minus` is zero and the other one is not.
+            #[must_use]
+            pub fn is_unilateral(&self) -> bool {
+                (self.plus == $tol::ZERO) != (self.minus == $tol::ZERO)
+            }
+
+            #[doc = concat!("Creates a `", stringify!($Self), "` with a symmetrical tolerance of `count` times `unit`.")]
+            ///
+            /// The sign of `count` is ignored, the tolerance is always `+/-`.
+            /// Fails with an `Overflow`, if the tolerance doesn't fit into the deviation type.
+            pub fn with_unit_tolerance(
+                value: impl Into<$value>,
+                unit: crate::Unit,
+                count: i32,
+            ) -> Result<Self, error::ToleranceError> {
+                let tol = i64::from(count)
+                    .checked_mul(*unit)
+                    .map(i64::abs)
+                    .ok_or_else(|| error::ToleranceError::Overflow(format!("{count} * {unit:?} overflows")))?;
+                let tol = $tol::try_from(tol).map_err(|_| {
+                    error::ToleranceError::Overflow(format!(
+                        "{count} * {unit:?} is to big for {}",
+                        stringify!($tol)
+                    ))
+                })?;
+                Ok(Self::with_sym(value, tol))
+            }
+
+            /// Creates a tolerance from a GD&T triple `(nominal, upper deviation, lower deviation)` in mm.
+            ///
+            /// Both deviations are signed relative to the nominal, the lower deviation may be
+            /// positive for a shifted tolerance. Fails with a `Validation` error on a number not
+            /// being finite or the upper deviation being below the lower one, and with an
+            /// `Overflow`, if a number is beyond the limits of its type.
+            pub fn from_gdt_triple(
+                nominal: f64,
+                upper_dev: f64,
+                lower_dev: f64,
+            ) -> Result<Self, error::ToleranceError> {
+                let check = |name: &str, v: f64, min: f64, max: f64, typ: &str| {
+                    if !v.is_finite() {
+                        return Err(error::ToleranceError::Validation(format!(
+                            "The {name} {v} is not a finite number."
+                        )));
+                    }
+                    if v < min || v > max {
+                        return Err(error::ToleranceError::Overflow(format!(
+                            "The {name} {v} is beyond the limits of {typ}."
+                        )));
+                    }
+                    Ok(())
+                };
+                let (min, max) = ($value::MIN.as_f64(), $value::MAX.as_f64());
+                check("nominal", nominal, min, max, stringify!($value))?;
+                let (min, max) = ($tol::MIN.as_f64(), $tol::MAX.as_f64());
+                check("upper deviation", upper_dev, min, max, stringify!($tol))?;
+                check("lower deviation", lower_dev, min, max, stringify!($tol))?;
+                if upper_dev < lower_dev {
+                    return Err(error::ToleranceError::Validation(format!(
+                        "Upper deviation {upper_dev} is below the lower deviation {lower_dev}."
+                    )));
+                }
+                Ok(Self::new(nominal, upper_dev, lower_dev))
+            }
+
+            /// Formats like `"  100.000 ±0.050"` for tables: the value right-aligned in
+            /// `nominal_width` columns, a symmetric tolerance with a single `±`.
+            ///
+            /// `decimals` is limited to 4, the precision of the `Myth`-types.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let t = ", stringify!($Self), "::with_sym(100.0, 0.05);")]
+            /// assert_eq!(t.to_aligned_string(9, 3), "  100.000 ±0.050");
+            #[doc = concat!("let t = ", stringify!($Self), "::new(5.0, 0.1, 0.0);")]
+            /// assert_eq!(t.to_aligned_string(9, 3), "    5.000 +0.100/-0.000");
+            /// ```
+            #[must_use]
+            pub fn to_aligned_string(&self, nominal_width: usize, decimals: usize) -> String {
+                let $Self { value, plus, minus } = self;
+                if *plus == -*minus && !plus.is_negative() {
+                    format!("{value:>nominal_width$.decimals$} ±{plus:.decimals$}")
+                } else {
+                    let m = if minus.0 > 0 { "+" } else if minus.0 == 0 { "-" } else { "" };
+                    format!("{value:>nominal_width$.decimals$} {plus:+.decimals$}/{m}{minus:.decimals$}")
+                }
+            }
+
+            /// Formats the value in the `primary` and in brackets in the `secondary` unit, like
+            /// on dual dimensioned drawings. The tolerances are given in the `primary` unit.
+            ///
+            /// ```rust
+            /// # use tolerance::Unit;
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let t = ", stringify!($Self), "::new(100.0, 0.05, -0.2);")]
+            /// assert_eq!(t.to_dual_string(Unit::MM, Unit::INCH), "100.0 mm [3.937 in] +0.05/-0.2");
+            /// ```
+            pub fn to_dual_string(&self, primary: crate::Unit, secondary: crate::Unit) -> String {
+                let number = |v: f64, decimals: usize| {
+                    let s = format!("{v:.decimals$}");
+                    let trimmed = s.trim_end_matches('0');
+                    if trimmed.ends_with('.') {
+                        format!("{trimmed}0")
+                    } else {
+                        trimmed.to_string()
+                    }
+                };
+                let symbol = |u: crate::Unit| u.symbol().map(|s| format!(" {s}")).unwrap_or_default();
+                let plus = number(self.plus.as_unit(primary), 4);
+                let minus = number(self.minus.as_unit(primary), 4);
+                format!(
+                    "{}{} [{}{}] {}{plus}/{}{minus}",
+                    number(self.value.as_unit(primary), 4),
+                    symbol(primary),
+                    number(self.value.as_unit(secondary), 3),
+                    symbol(secondary),
+                    if self.plus.is_negative() { "" } else { "+" },
+                    if self.minus.0 > 0 { "+" } else if self.minus.0 == 0 { "-" } else { "" },
+                )
+            }
+
+            /// Returns the GD&T triple `(nominal, upper deviation, lower deviation)` in mm.
+            #[must_use]
+            pub fn to_gdt_triple(&self) -> (f64, f64, f64) {
+                (self.value.as_f64(), self.plus.as_f64(), self.minus.as_f64())
+            }
+
+            #[doc = concat!("Creates a `", stringify!($Self), "` from its limits, the value is centered between them.")]
+            ///
+            /// Fails, if `lower` is above `upper` or the tolerance does not fit.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let t = ", stringify!($Self), "::from_limits(9.8, 10.2).unwrap();")]
+            #[doc = concat!("assert_eq!(t, ", stringify!($Self), "::with_sym(10.0, 0.2));")]
+            /// ```
+            pub fn from_limits(
+                lower: impl Into<$value>,
+                upper: impl Into<$value>,
+            ) -> Result<Self, error::ToleranceError> {
+                let (lower, upper) = (lower.into(), upper.into());
+                if lower > upper {
+                    return Err(error::ToleranceError::Validation(format!(
+                        "Lower limit {lower} is above the upper limit {upper}."
+                    )));
+                }
+                let (l, u) = (lower.as_i64() as i128, upper.as_i64() as i128);
+                let value = l + (u - l) / 2;
+                let overflow = |_| {
+                    error::ToleranceError::Overflow(format!(
+                        "The limits {lower} and {upper} do not fit into a {}.",
+                        stringify!($Self)
+                    ))
+                };
+                Ok(Self {
+                    value: $value::try_from(value).map_err(overflow)?,
+                    plus: $tol::try_from(u - value).map_err(overflow)?,
+                    minus: $tol::try_from(l - value).map_err(overflow)?,
+                })
+            }
+
+            /// Moves the value by `delta`, the deviations are kept and the limits move along.
+            ///
+            /// | method                     | value         | deviations  | limits      |
+            /// |----------------------------|---------------|-------------|-------------|
+            /// | `shift_keeping_deviations` | `+ delta`     | kept        | `+ delta`   |
+            /// | `shift_keeping_limits`     | `new_nominal` | recomputed  | kept        |
+            pub fn shift_keeping_deviations(&self, delta: impl Into<$value>) -> Self {
+                Self {
+                    value: self.value + delta.into(),
+                    ..*self
+                }
+            }
+
+            /// Sets the value to `new_nominal`, the limits are kept and the deviations recomputed.
+            ///
+            /// Fails, if a deviation does not fit into the tolerance type.
+            /// See [`shift_keeping_deviations`](#method.shift_keeping_deviations).
+            pub fn shift_keeping_limits(
+                &self,
+                new_nominal: impl Into<$value>,
+            ) -> Result<Self, error::ToleranceError> {
+                let value = new_nominal.into();
+                let deviation = |limit: $value| {
+                    $tol::try_from(limit.as_i64() as i128 - value.as_i64() as i128).map_err(|_| {
+                        error::ToleranceError::Overflow(format!(
+                            "The limit {limit} is too far away from {value}."
+                        ))
+                    })
+                };
+                Ok(Self {
+                    value,
+                    plus: deviation(self.upper_limit())?,
+                    minus: deviation(self.lower_limit())?,
+                })
+            }
+
+            /// Measures the tolerance from `new_datum` instead of `old_datum`, the whole band
+            /// moves by `old_datum - new_datum`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let t = ", stringify!($Self), "::with_sym(30.0, 0.1);")]
+            #[doc = concat!("assert_eq!(t.rereference(0.0, 10.0), ", stringify!($Self), "::with_sym(20.0, 0.1));")]
+            /// ```
+            pub fn rereference(
+                &self,
+                old_datum: impl Into<$value>,
+                new_datum: impl Into<$value>,
+            ) -> Self {
+                self.shift_keeping_deviations(old_datum.into() - new_datum.into())
+            }
+
             #[doc = concat!("Narrows a `", stringify!($Self), "` to the given tolerance.")]
             pub fn narrow(&self, plus: impl Into<$tol>, minus: impl Into<$tol>) -> Self {
                 Self::new(self.value, plus, minus)
@@ -70,6 +378,68 @@ macro_rules! tolerance_body {
                 Self::new(self.value, tol, -tol)
             }
 
+            /// [Rounds](./struct.Myth64.html#method.round) the `value` to the given `Unit`,
+            /// `plus` and `minus` are kept.
+            pub fn round_value(&self, unit: crate::Unit) -> Self {
+                Self {
+                    value: self.value.round(unit),
+                    ..*self
+                }
+            }
+
+            /// [Floors](./struct.Myth64.html#method.floor) the `value` to the given `Unit`,
+            /// `plus` and `minus` are kept.
+            pub fn floor_value(&self, unit: crate::Unit) -> Self {
+                Self {
+                    value: self.value.floor(unit),
+                    ..*self
+                }
+            }
+
+            /// [Ceils](./struct.Myth64.html#method.ceil) the `value` to the given `Unit`,
+            /// `plus` and `minus` are kept.
+            pub fn ceil_value(&self, unit: crate::Unit) -> Self {
+                Self {
+                    value: self.value.ceil(unit),
+                    ..*self
+                }
+            }
+
+            /// Restricts the `value` to `min..=max`, `plus` and `minus` are kept.
+            ///
+            /// ### Panics
+            /// If `min > max`.
+            pub fn clamp_value(&self, min: impl Into<$value>, max: impl Into<$value>) -> Self {
+                Self {
+                    value: self.value.clamp(min.into(), max.into()),
+                    ..*self
+                }
+            }
+
+            /// Rounds `plus` and `minus` outwards to the nearest multiple of one of the
+            /// `increments`, so the result never is tighter than `self`. On a tie the first
+            /// (coarser) increment wins.
+            pub fn round_to_increments(&self, increments: &[crate::Unit]) -> Self {
+                let outward = |v: $tol, up: bool| {
+                    increments
+                        .iter()
+                        .map(|u| if up { v.ceil(*u) } else { v.floor(*u) })
+                        .min_by_key(|r| (*r - v).abs())
+                        .unwrap_or(v)
+                };
+                Self {
+                    value: self.value,
+                    plus: outward(self.plus, true),
+                    minus: outward(self.minus, false),
+                }
+            }
+
+            /// Rounds `plus` and `minus` outwards to the
+            /// [`Unit::STANDARD_INCREMENTS`](./struct.Unit.html#associatedconstant.STANDARD_INCREMENTS).
+            pub fn round_to_standard(&self) -> Self {
+                self.round_to_increments(&crate::Unit::STANDARD_INCREMENTS)
+            }
+
             #[doc = concat!("Returns the maximum allowed value of this ", stringify!($Self), ".")]
             pub fn upper_limit(&self) -> $value {
                 self.value + self.plus
@@ -81,6 +451,177 @@ macro_rules! tolerance_body {
                 self.value + self.minus
             }
 
+            /// Makes a nearly symmetric tolerance exactly symmetric (`minus = -plus`), when
+            /// `|plus + minus| <= epsilon`. Otherwise `self` is returned unchanged.
+            ///
+            /// A band entirely below the value (negative `plus`) is never changed, it has no
+            /// symmetric counterpart with the same `plus`.
+            pub fn simplify(&self, epsilon: impl Into<$tol>) -> Self {
+                let skew = (self.plus.as_i64() + self.minus.as_i64()).abs();
+                if !self.plus.is_negative() && skew <= epsilon.into().as_i64() {
+                    Self {
+                        minus: -self.plus,
+                        ..*self
+                    }
+                } else {
+                    *self
+                }
+            }
+
+            /// Returns `plus` and `minus` in mm, both keep their sign.
+            #[must_use]
+            pub fn deviations_mm(&self) -> (f64, f64) {
+                (self.plus.as_f64(), self.minus.as_f64())
+            }
+
+            /// Returns the lower and the upper limit as tolerances without `plus` and `minus`.
+            pub fn limits(&self) -> (Self, Self) {
+                (Self::from(self.lower_limit()), Self::from(self.upper_limit()))
+            }
+
+            /// Returns the total width of the tolerance band (`plus - minus`).
+            ///
+            #[doc = concat!("Returned as `", stringify!($value), "`, a band of two maximal deviations is wider than")]
+            #[doc = concat!("a `", stringify!($tol), "`.")]
+            pub fn width(&self) -> $value {
+                $value::from(self.plus) - $value::from(self.minus)
+            }
+
+            /// The width of the band as `i64`, without any overflow.
+            fn width_i64(&self) -> i64 {
+                self.plus.as_i64() - self.minus.as_i64()
+            }
+
+            /// Returns the expected value assuming a uniform distribution within the band,
+            /// the [`midpoint`](#method.midpoint).
+            pub fn expected_value(&self) -> $value {
+                self.midpoint()
+            }
+
+            /// Returns the expected value assuming a normal distribution around the nominal,
+            /// the `value` itself.
+            pub fn expected_value_normal(&self) -> $value {
+                self.value
+            }
+
+            /// Returns `true`, if `plus == -minus`.
+            #[must_use]
+            pub fn is_symmetric(&self) -> bool {
+                self.plus == -self.minus
+            }
+
+            /// Returns the symmetric tolerance of the same band: the `value` is moved to the
+            /// [`midpoint`](#method.midpoint), the tolerance is half the [`span`](#method.span).
+            ///
+            /// On an odd span in 0.1μ the tolerance is rounded up, so the band is never tighter.
+            ///
+            /// ### Panics
+            #[doc = concat!("If the rounded up half span does not fit into a `", stringify!($tol), "`, only possible for")]
+            /// a band from `MIN` to `MAX`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let t = ", stringify!($Self), "::new(100.0, 0.3, -0.1);")]
+            #[doc = concat!("assert_eq!(t.to_symmetric(), ", stringify!($Self), "::with_sym(100.1, 0.2));")]
+            /// ```
+            pub fn to_symmetric(&self) -> Self {
+                let width = self.width_i64();
+                let half = $tol::try_from((width - width / 2) as i128).expect("Tolerance out of scope");
+                Self {
+                    value: self.midpoint(),
+                    plus: half,
+                    minus: -half,
+                }
+            }
+
+            /// Returns the total width of the tolerance band, the same as [`width`](#method.width).
+            pub fn span(&self) -> $value {
+                self.width()
+            }
+
+            /// Returns the center of the tolerance band: `value + (plus + minus) / 2`.
+            pub fn midpoint(&self) -> $value {
+                let offset = (self.plus.as_i64() + self.minus.as_i64()) / 2;
+                self.value + $tol::try_from(offset as i128).expect("half a sum always fits")
+            }
+
+            /// Returns the half width of the tolerance band, the symmetric equivalent tolerance.
+            pub fn half_width(&self) -> $tol {
+                $tol::try_from((self.width_i64() / 2) as i128).expect("half a band always fits")
+            }
+
+            /// Returns the process capability index `Cp = width / (6 * sigma)` for the
+            /// measured standard deviation `sigma`.
+            #[must_use]
+            pub fn cp(&self, sigma: $tol) -> f64 {
+                self.width_i64() as f64 / (6.0 * sigma.as_i64() as f64)
+            }
+
+            /// Returns the skew of the tolerance `(plus + minus) / width`, `0.0` for a symmetric
+            /// tolerance, `1.0` or `-1.0` for a tolerance fully above or below the value.
+            ///
+            /// Tolerances not containing the value exceed these bounds, a tolerance without width
+            /// returns `0.0`.
+            #[must_use]
+            pub fn asymmetry(&self) -> f64 {
+                let width = self.plus.as_i64() - self.minus.as_i64();
+                if width == 0 {
+                    return 0.0;
+                }
+                (self.plus.as_i64() + self.minus.as_i64()) as f64 / width as f64
+            }
+
+            /// Models a process drift: moves the value by `shift_fraction * half_width()` and
+            /// keeps the deviations, so the whole band moves along.
+            ///
+            /// `shift_fraction` is clamped to `[-1.0, 1.0]`, the shift is rounded to 0.1μ.
+            pub fn with_process_shift(&self, shift_fraction: f64) -> Self {
+                let shift = shift_fraction.clamp(-1.0, 1.0) * (self.width_i64() / 2) as f64;
+                Self {
+                    value: self.value + $value::from(shift.round() as i32),
+                    ..*self
+                }
+            }
+
+            /// Multiplies `plus` and `minus` by `factor`, the `value` is kept.
+            ///
+            /// ### Panics
+            /// If `factor` is negative or not finite, or the tolerance gets out of scope.
+            pub fn scale_tolerance(&self, factor: f64) -> Self {
+                assert!(
+                    factor.is_finite() && factor >= 0.0,
+                    "factor has to be a positive number, not {factor}."
+                );
+                let scale = |t: $tol| {
+                    $tol::try_from((t.as_i64() as f64 * factor).round() as i128)
+                        .expect("Tolerance out of scope")
+                };
+                Self {
+                    value: self.value,
+                    plus: scale(self.plus),
+                    minus: scale(self.minus),
+                }
+            }
+
+            /// Widens the band of `worst_case` by a safety `factor`, the `value` is kept.
+            ///
+            /// Unlike [`scale_tolerance`](#method.scale_tolerance) this never panics: a negative
+            /// or NaN factor is taken as `0.0`, and `plus` and `minus` saturate at the limits
+            #[doc = concat!("of `", stringify!($tol), "`.")]
+            pub fn with_safety_factor(worst_case: Self, factor: f64) -> Self {
+                let factor = factor.max(0.0);
+                let (min, max) = ($tol::MIN.as_i64() as f64, $tol::MAX.as_i64() as f64);
+                let scale = |t: $tol| {
+                    let scaled = (t.as_i64() as f64 * factor).round().clamp(min, max);
+                    $tol::try_from(scaled as i128).expect("clamped into range")
+                };
+                Self {
+                    value: worst_case.value,
+                    plus: scale(worst_case.plus),
+                    minus: scale(worst_case.minus),
+                }
+            }
+
             /// Returns `true`, if `self` is more narrow than the `other`.
             #[must_use]
             pub fn is_inside_of(&self, other: Self) -> bool {
@@ -96,6 +637,107 @@ macro_rules! tolerance_body {
                     && self.upper_limit() >= other.upper_limit()
             }
 
+            /// Returns `true`, if `self` is less strict (around) the `other`.
+            #[deprecated(since = "1.2.0", note = "use `enfold` instead")]
+            #[must_use]
+            pub fn embrace(&self, other: impl Into<$Self>) -> bool {
+                self.enfold(other)
+            }
+
+            /// Returns `true`, if `self` is strictly wider than `other` on both ends, unlike
+            /// [`enfold`](#method.enfold) a shared limit is not enough.
+            #[must_use]
+            pub fn contains_band_strict(&self, other: impl Into<$Self>) -> bool {
+                let other = other.into();
+                self.lower_limit() < other.lower_limit()
+                    && self.upper_limit() > other.upper_limit()
+            }
+
+            /// Returns the tolerance with the narrower band, on equal widths the one with the
+            /// smaller value.
+            pub fn tighter(self, other: Self) -> Self {
+                std::cmp::min_by_key(self, other, |t| (t.width_i64(), t.value))
+            }
+
+            /// Returns the tolerance with the wider band, on equal widths the one with the
+            /// bigger value.
+            pub fn looser(self, other: Self) -> Self {
+                std::cmp::max_by_key(self, other, |t| (t.width_i64(), t.value))
+            }
+
+            /// Returns the window of values accepted by all `parts` as `(lower, upper)`.
+            ///
+            /// This is the intersection of all tolerances. Returns `None` if `parts` is empty
+            /// or the intersection is empty.
+            #[must_use]
+            pub fn combined_window(parts: &[Self]) -> Option<($value, $value)> {
+                let lower = parts.iter().map(Self::lower_limit).max()?;
+                let upper = parts.iter().map(Self::upper_limit).min()?;
+                (lower <= upper).then_some((lower, upper))
+            }
+
+            /// Returns the band accepted by `self` and `other`, centered between its limits.
+            /// Returns `None`, if the bands do not overlap.
+            ///
+            /// ```rust
+            #[doc = concat!("# use tolerance::", stringify!($Self), ";")]
+            #[doc = concat!("let a = ", stringify!($Self), "::new(10.0, 0.2, -0.1);")]
+            #[doc = concat!("let b = ", stringify!($Self), "::with_sym(10.2, 0.1);")]
+            #[doc = concat!("assert_eq!(a.intersection(&b), Some(", stringify!($Self), "::with_sym(10.15, 0.05)));")]
+            /// ```
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let (lower, upper) = Self::combined_window(&[*self, *other])?;
+                Self::from_limits(lower, upper).ok()
+            }
+
+            /// Returns the smallest band enclosing `self` and `other`, centered between its
+            /// limits. Unlike [`intersection`](#method.intersection) there is always a hull.
+            ///
+            /// ### Panics
+            #[doc = concat!("If the hull is too wide to fit into a `", stringify!($Self), "`.")]
+            pub fn hull(&self, other: &Self) -> Self {
+                Self::from_limits(
+                    self.lower_limit().min(other.lower_limit()),
+                    self.upper_limit().max(other.upper_limit()),
+                )
+                .expect("Hull out of scope")
+            }
+
+            /// Returns `true`, if `lower_limit() <= measured <= upper_limit()`.
+            #[must_use]
+            pub fn contains(&self, measured: impl Into<$value>) -> bool {
+                let measured = measured.into();
+                self.lower_limit() <= measured && measured <= self.upper_limit()
+            }
+
+            /// Returns `true`, if the `measured` value is within the limits of this spec.
+            /// Values on a limit are accepted, the same as [`contains`](#method.contains).
+            #[must_use]
+            pub fn accepts(&self, measured: impl Into<$value>) -> bool {
+                self.contains(measured)
+            }
+
+            /// Returns a human-readable reason, why the `measured` value is rejected by this spec.
+            /// Returns `None`, if the value is [accepted](#method.accepts).
+            ///
+            /// ```rust
+            /// # use tolerance::T128;
+            /// let spec = T128::new(10.0, 0.05, -0.02);
+            /// assert_eq!(spec.reject_reason(10.08), Some("0.03 mm over upper limit".to_string()));
+            /// assert_eq!(spec.reject_reason(10.0), None);
+            /// ```
+            #[must_use]
+            pub fn reject_reason(&self, measured: impl Into<$value>) -> Option<String> {
+                let measured = measured.into();
+                if measured > self.upper_limit() {
+                    Some(format!("{} mm over upper limit", measured - self.upper_limit()))
+                } else if measured < self.lower_limit() {
+                    Some(format!("{} mm under lower limit", self.lower_limit() - measured))
+                } else {
+                    None
+                }
+            }
+
             #[doc = concat!("Inverts this `", stringify!($Self), "`.")]
             /// Interchanges the `plus` and `minus` parts.
             /// Required when measuring back in the opposite direction.
@@ -109,6 +751,33 @@ macro_rules! tolerance_body {
                 }
             }
 
+            /// Returns the tolerance with the absolute `value`. A negative value is
+            /// [inverted](#method.invert), so `plus` and `minus` are swapped and negated.
+            pub fn abs(&self) -> Self {
+                if self.value.is_negative() {
+                    self.invert()
+                } else {
+                    *self
+                }
+            }
+
+            /// Returns the [`signum`](./struct.Myth64.html#method.signum) of the `value`.
+            pub fn signum(&self) -> $value {
+                self.value.signum()
+            }
+
+            /// Returns the stable type tag, the name of the type.
+            #[must_use]
+            pub const fn kind(&self) -> &'static str {
+                stringify!($Self)
+            }
+
+            /// Returns the length of the byte representation, like in `to_be_bytes`.
+            #[must_use]
+            pub const fn byte_len(&self) -> usize {
+                std::mem::size_of::<$Self>()
+            }
+
             #[doc = concat!("Returns the memory representation of this ", stringify!($Self), " as a byte array in")]
             /// big-endian (network) byte order.
             #[must_use]
@@ -200,22 +869,24 @@ macro_rules! tolerance_body {
         }
 
         #[doc = concat!("Inverts the signs of all fields in this `", stringify!($Self), "`.")]
-        /// Like multiplying by `-1`.
+        /// Like multiplying by `-1`, `plus` and `minus` are interchanged to keep `plus` above
+        /// `minus`. Same as `!value`.
         impl Neg for $Self {
             type Output = Self;
 
             fn neg(self) -> Self::Output {
-                Self::new(-self.value, -self.plus, -self.minus)
+                self.invert()
             }
         }
 
         #[doc = concat!("Inverts the signs of all fields in this `", stringify!($Self), "`.")]
-        /// Like multiplying by `-1`.
+        /// Like multiplying by `-1`, `plus` and `minus` are interchanged to keep `plus` above
+        /// `minus`. Same as `!value`.
         impl <'a> Neg for &'a $Self {
             type Output = $Self;
 
             fn neg(self) -> Self::Output {
-                $Self::new(-self.value, -self.plus, -self.minus)
+                self.invert()
             }
         }
 
@@ -552,6 +1223,10 @@ macro_rules! tolerance_body {
         /// * Values are interpreted as *mm* — the point and decimal places can be omitted. (`140` => `140.0000`)
         /// * A leading zero can be omitted. (`.04` => `0.0400`)
         /// * Possible divider between the 3 parts are `' '` (blank #32), `/` or `;`.
+        /// * A symmetric tolerance can be written as `+/-`, `+-` or `±`.
+        /// * The tolerance may be in parentheses. (`100.0 (±0.1)`, `100.0 (+0.2/-0.1)`)
+        /// * The value may carry a unit, glued or separated by a blank, used for all parts without
+        ///   an own unit. (`1in +0.001/-0.002`, `100 mm ±0.1`)
         /// * 3 parts  =>  value, plus, minus
         /// * 2 parts  =>  value, plus, -plus
         /// * 1 part   =>  value, 0.0, 0.0
@@ -561,9 +1236,33 @@ macro_rules! tolerance_body {
 
                 // Required method
                 fn from_str(text: &str) -> Result<Self, Self::Err> {
-                    let s = text.replace("+/-", " ").replace("+-", " ").replace('/', " ").replace(';', " ");
-                    let parts: Vec<Result<i64, Self::Err>> = s.split_whitespace().map(| part | {
-                        crate::try_from_str(part, &stringify!($Self))
+                    // the tolerance may be written in parentheses: `100.0 (+0.2/-0.1)`
+                    let s = match (text.matches('(').count(), text.matches(')').count()) {
+                        (0, 0) => text.to_string(),
+                        (1, 1) if text.find('(') < text.find(')') && text.trim_end().ends_with(')') => {
+                            text.replace(['(', ')'], " ")
+                        }
+                        _ => return Err(ParseError(format!("Unmatched parentheses in '{text}'!"))),
+                    };
+                    // a symmetric deviation carries no sign of its own: `±-0.1` would invert the band
+                    for marker in ["+/-", "+-", "\u{b1}"] {
+                        if s.match_indices(marker).any(|(i, _)| s[i + marker.len()..].trim_start().starts_with(['+', '-'])) {
+                            return Err(ParseError(format!("Signed deviation after '{marker}' in '{text}'!")));
+                        }
+                    }
+                    let s = s.replace("+/-", " ").replace("+-", " ").replace('\u{b1}', " ").replace('/', " ").replace(';', " ");
+                    let mut tokens: Vec<String> = s.split_whitespace().map(String::from).collect();
+                    // the unit of the value may be separated by a blank: `100 mm ±0.1`
+                    if tokens.len() > 1 && crate::Unit::from_suffix(&tokens[1]).is_some() {
+                        let suffix = tokens.remove(1);
+                        tokens[0].push_str(&suffix);
+                    }
+                    // the unit of the value is used for all parts without an own unit.
+                    let unit = tokens.first()
+                        .and_then(|v| crate::split_unit(v).ok()?.1)
+                        .unwrap_or(crate::Unit::MM);
+                    let parts: Vec<Result<i64, Self::Err>> = tokens.iter().map(| part | {
+                        crate::try_from_str_in(part, unit, &stringify!($Self))
                     }).collect();
                     if parts.iter().find(|r| r.is_err()).is_some() {
                         return Err(ParseError(format!("{} not parsable from '{text}'!", stringify!($Self))))
@@ -576,6 +1275,32 @@ macro_rules! tolerance_body {
                 }
         }
 
+        /// Reads `value`, `plus` and `minus` (or `v`, `p` and `m`) in mm, like the deserializer
+        /// a missing `plus` is `0.0` and a missing `minus` is `-plus`.
+        impl TryFrom<&std::collections::HashMap<String, f64>> for $Self {
+            type Error = error::ToleranceError;
+
+            fn try_from(map: &std::collections::HashMap<String, f64>) -> Result<Self, Self::Error> {
+                let mut fields = Fields::default();
+                for (key, &f) in map {
+                    let field = Field::from_name(key).ok_or_else(|| {
+                        ParseError(format!("Unknown field `{key}`, expected one of {:?}!", Field::NAMES))
+                    })?;
+                    if fields.is_set(field) {
+                        return Err(ParseError(format!("Duplicate field `{}`!", field.name())));
+                    }
+                    match field {
+                        Field::Value => fields.value = Some($value::try_from_mm(f)?),
+                        Field::Plus => fields.plus = Some($tol::try_from_mm(f)?),
+                        Field::Minus => fields.minus = Some($tol::try_from_mm(f)?),
+                    }
+                }
+                fields
+                    .build()
+                    .ok_or_else(|| ParseError(format!("Missing field `value` for {}!", stringify!($Self))))
+            }
+        }
+
         impl TryFrom<(Option<&i32>, Option<&i32>, Option<&i32>)> for $Self {
             type Error = error::ToleranceError;
 
@@ -640,6 +1365,18 @@ macro_rules! de_serde_tol {
             Deserialize, Deserializer, Serialize, Serializer,
         };
 
+        /// Converts every form the deserializer accepts: a string, a number, an array or an object.
+        #[cfg(feature = "serde_json")]
+        impl TryFrom<&serde_json::Value> for $Self {
+            type Error = error::ToleranceError;
+
+            fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+                $Self::deserialize(value).map_err(|e| {
+                    ParseError(format!("{} not convertible from '{value}': {e}", stringify!($Self)))
+                })
+            }
+        }
+
         impl Serialize for $Self {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
@@ -653,49 +1390,38 @@ macro_rules! de_serde_tol {
                 }
         }
 
-        impl<'de> Deserialize<'de> for $Self {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                enum Field {
-                    Value,
-                    Plus,
-                    Minus,
-                }
-                impl<'de> Deserialize<'de> for Field {
-                    fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
-                    where
-                        D: Deserializer<'de>,
-                    {
-                        struct FieldVisitor;
-
-                        impl<'de> Visitor<'de> for FieldVisitor {
-                            type Value = Field;
+                struct FieldVisitor;
 
-                            fn expecting(
-                                &self,
-                                formatter: &mut std::fmt::Formatter,
-                            ) -> std::fmt::Result {
-                                formatter.write_str("`value`, `plus` or `minus`")
-                            }
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = Field;
 
-                            fn visit_str<E>(self, value: &str) -> Result<Field, E>
-                            where
-                                E: serde::de::Error,
-                            {
-                                match value {
-                                    "value" | "v" => Ok(Field::Value),
-                                    "plus" | "p" => Ok(Field::Plus),
-                                    "minus" | "m" => Ok(Field::Minus),
-                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
-                                }
-                            }
-                        }
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        formatter.write_str("`value`, `plus` or `minus`")
+                    }
 
-                        deserializer.deserialize_identifier(FieldVisitor)
+                    fn visit_str<E>(self, value: &str) -> Result<Field, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        Field::from_name(value)
+                            .ok_or_else(|| serde::de::Error::unknown_field(value, &Field::NAMES))
                     }
                 }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Self {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
                 struct TolVisitor;
 
                 impl<'de> Visitor<'de> for TolVisitor {
@@ -798,38 +1524,18 @@ macro_rules! de_serde_tol {
                     where
                         A: MapAccess<'de>,
                     {
-                        let mut value = None;
-                        let mut plus = None;
-                        let mut minus = None;
-                        while let Some(key) = map.next_key()? {
+                        let mut fields = Fields::default();
+                        while let Some(key) = map.next_key::<Field>()? {
+                            if fields.is_set(key) {
+                                return Err(serde::de::Error::duplicate_field(key.name()));
+                            }
                             match key {
-                                Field::Value => {
-                                    if value.is_some() {
-                                        return Err(serde::de::Error::duplicate_field("value"));
-                                    }
-                                    value = Some(map.next_value()?);
-                                }
-                                Field::Plus => {
-                                    if plus.is_some() {
-                                        return Err(serde::de::Error::duplicate_field("plus"));
-                                    }
-                                    plus = Some(map.next_value()?);
-                                }
-                                Field::Minus => {
-                                    if minus.is_some() {
-                                        return Err(serde::de::Error::duplicate_field("minus"));
-                                    }
-                                    minus = Some(map.next_value()?);
-                                }
+                                Field::Value => fields.value = Some(map.next_value()?),
+                                Field::Plus => fields.plus = Some(map.next_value()?),
+                                Field::Minus => fields.minus = Some(map.next_value()?),
                             }
                         }
-
-                        let value : $Val = value.ok_or_else(|| serde::de::Error::missing_field("value"))?;
-                        let plus : $Tol = plus.unwrap_or($Tol::ZERO);
-                        let minus : $Tol = minus.unwrap_or(plus.neg());
-                        Ok($Self {
-                            value, plus, minus
-                        })
+                        fields.build().ok_or_else(|| serde::de::Error::missing_field("value"))
                     }
 
                     fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
@@ -851,7 +1557,12 @@ macro_rules! de_serde_tol {
                 }
 
                 const FIELDS: &[&str] = &["value", "plus", "minus"];
-                deserializer.deserialize_any(TolVisitor)
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(TolVisitor)
+                } else {
+                    // binary formats like `postcard` are not self-describing.
+                    deserializer.deserialize_struct(stringify!($Self), FIELDS, TolVisitor)
+                }
             }
         }
     };
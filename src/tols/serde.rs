@@ -212,6 +212,106 @@ macro_rules! impl_t_into_f64s {
 impl_t_into_f64s!(T128, "T128::into_float_struct", "T128::into_float_seq");
 impl_t_into_f64s!(T64, "T64::into_float_struct", "T64::into_float_seq");
 
+macro_rules! impl_t_into_drawing_strings {
+    ($Self:ident, $($fn_name:ident, $value_sep:literal, $tol_sep:literal, $json:literal),+) => {
+        impl $Self {
+            /// Writes the drawing-style string, always with both tolerances.
+            fn drawing_string(&self, value_sep: char, tol_sep: char) -> String {
+                let $Self { value, plus, minus } = self;
+                let m = if minus.0 > 0 { "+" } else if minus.0 == 0 { "-" } else { "" };
+                format!("{value}{value_sep}{plus:+}{tol_sep}{m}{minus}")
+            }
+            $(
+                #[doc = concat!("Serializes a `", stringify!($Self), "` into a drawing-style string with both tolerances.")]
+                /// ```json
+                #[doc = concat!("\"width\": \"", $json, "\"")]
+                /// ```
+                /// ### Example
+                /// ```rust
+                ///# use serde::*;
+                ///# use tolerance::*;
+                ///#
+                /// #[derive(Serialize)]
+                /// struct T2 {
+                #[doc = concat!("     #[serde(serialize_with = \"", stringify!($Self), "::", stringify!($fn_name), "\")]")]
+                #[doc = concat!("     width: ", stringify!($Self), ",")]
+                /// }
+                /// let t = T2 {
+                #[doc = concat!("     width: ", stringify!($Self), "::with_sym(10.0, 0.1),")]
+                /// };
+                /// assert_eq!(
+                #[doc = concat!("     r#\"{\"width\":\"", $json, "\"}\"#,")]
+                ///     serde_json::to_string(&t).unwrap()
+                /// );
+                /// ```
+                pub fn $fn_name<S>(t: &$Self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(&t.drawing_string($value_sep, $tol_sep))
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! impl_drawing_strings {
+    ($($Self:ident),+) => {
+        $(
+            impl_t_into_drawing_strings!(
+                $Self,
+                into_slash_string, '/', '/', "10.0/+0.1/-0.1",
+                into_space_string, ' ', ' ', "10.0 +0.1 -0.1",
+                into_asym_string, ' ', '/', "10.0 +0.1/-0.1"
+            );
+        )+
+    };
+}
+
+impl_drawing_strings!(T128, T64);
+
+macro_rules! impl_myth_into_number {
+    ($Self:ident) => {
+        impl $Self {
+            #[doc = concat!("Serializes a `", stringify!($Self), "` into a plain integer of mm when exact, otherwise into a float of mm.")]
+            /// ```json
+            /// "width": 100
+            /// "length": 100.5
+            /// ```
+            /// ⚠ The default deserializer reads integers as "1/10 μ", so use this for output only.
+            /// ### Example
+            /// ```rust
+            ///# use serde::*;
+            ///# use tolerance::*;
+            ///#
+            /// #[derive(Serialize)]
+            /// struct T2 {
+            #[doc = concat!("     #[serde(serialize_with = \"", stringify!($Self), "::as_int_or_float\")]")]
+            #[doc = concat!("     width: ", stringify!($Self), ",")]
+            /// }
+            /// let t = T2 {
+            #[doc = concat!("     width: ", stringify!($Self), "::from(1.5),")]
+            /// };
+            /// assert_eq!(r#"{"width":1.5}"#, serde_json::to_string(&t).unwrap());
+            /// ```
+            pub fn as_int_or_float<S>(t: &$Self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                if t.0 % $Self::ONE.0 == 0 {
+                    serializer.serialize_i64(t.as_i64() / $Self::ONE.as_i64())
+                } else {
+                    serializer.serialize_f64(t.as_f64())
+                }
+            }
+        }
+    };
+}
+
+impl_myth_into_number!(Myth64);
+impl_myth_into_number!(Myth32);
+impl_myth_into_number!(Myth16);
+
 macro_rules! empty_to_case {
     ($Self:ident) => {
         impl $Self {
@@ -2,11 +2,11 @@ use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::iter::Sum;
-use std::ops::{Add, AddAssign, Mul, Neg, Not, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Not, Sub, SubAssign};
 use std::str::FromStr;
 
 use crate::error::ToleranceError::ParseError;
-use crate::{error, Myth32, Myth64};
+use crate::{error, Myth32, Myth64, T64};
 
 /// # 128bit tolerance-type
 ///
@@ -43,13 +43,18 @@ use crate::{error, Myth32, Myth64};
 /// assert_eq!(T128::from_str("12.0 +-0.4"), Ok(T128::new(12.0, 0.4, -0.4)));
 /// // 12.0 +/- 0
 /// assert_eq!(T128::try_from("12.0"), Ok(T128::from(12.0)));
+/// // with a unit, used for all parts without an own one
+/// assert_eq!("1in +0.001/-0.002".parse(), Ok(T128::new(25.4, 0.0254, -0.0508)));
 /// ```
 ///
+/// The layout is `#[repr(C)]`: `value`, `plus` and `minus` in 16 bytes without padding.
+///
 #[cfg_attr(
     feature = "serde",
     doc = include_str!("serde.md")
 )]
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
 #[must_use]
 pub struct T128 {
     #[cfg_attr(feature = "serde", doc = "In deserialization `value` or `v` is used.")]
@@ -62,15 +67,300 @@ pub struct T128 {
 
 super::tolerance_body!(T128, Myth64, Myth32);
 super::multiply_tolerance!(T128, u64, u32, u16, u8, i64, i32);
+super::divide_tolerance!(T128, u64, u32, u16, u8, i64, i32);
 #[cfg(feature = "serde")]
 super::de_serde_tol!(T128, Myth64, Myth32);
 
+impl T128 {
+    /// Splits `total_tol` across components proportionally to their `weights`.
+    ///
+    /// The split is rounded on the running total, so the parts always sum up exactly to
+    /// `total_tol` and the rounding remainder is assigned deterministically.
+    ///
+    /// ```rust
+    /// # use tolerance::{Myth32, T128};
+    /// let parts = T128::allocate_budget(Myth32::from(0.1), &[1.0, 1.0, 1.0]);
+    /// assert_eq!(parts, vec![Myth32::from(0.0333), Myth32::from(0.0334), Myth32::from(0.0333)]);
+    /// ```
+    ///
+    /// ### Panics
+    /// If a weight is negative or all weights are zero.
+    #[must_use]
+    pub fn allocate_budget(total_tol: Myth32, weights: &[f64]) -> Vec<Myth32> {
+        if weights.is_empty() {
+            return Vec::new();
+        }
+        assert!(
+            weights.iter().all(|w| *w >= 0.0),
+            "Weights have to be positive."
+        );
+        let sum: f64 = weights.iter().sum();
+        assert!(sum > 0.0, "At least one weight has to be positive.");
+        let total = f64::from(total_tol.0);
+        let mut cumulated = 0.0;
+        let mut previous = 0;
+        weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                cumulated += w;
+                let boundary = if i + 1 == weights.len() {
+                    total_tol.0
+                } else {
+                    (total * cumulated / sum).round() as i32
+                };
+                let part = Myth32(boundary - previous);
+                previous = boundary;
+                part
+            })
+            .collect()
+    }
+
+    /// Checks if the ordered `positions` stay ordered in the worst case, `false` if any two
+    /// consecutive positions could overlap within their tolerances.
+    ///
+    /// ```rust
+    /// # use tolerance::T128;
+    /// let holes = [T128::with_sym(10.0, 0.1), T128::with_sym(10.3, 0.1)];
+    /// assert!(T128::chain_is_monotonic(&holes));
+    /// let holes = [T128::with_sym(10.0, 0.2), T128::with_sym(10.3, 0.2)];
+    /// assert!(!T128::chain_is_monotonic(&holes));
+    /// ```
+    pub fn chain_is_monotonic(positions: &[T128]) -> bool {
+        positions
+            .windows(2)
+            .all(|w| w[1].lower_limit() >= w[0].upper_limit())
+    }
+}
+
+/// The breakdown of a tolerance stack, created by [`T128::stack_report`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StackReport {
+    /// The sum of all nominal values.
+    pub nominal: Myth64,
+    /// The worst-case band width, the sum of all widths.
+    pub worst_case: Myth64,
+    /// The statistical band width, the root of the sum of all squared widths.
+    pub rss: Myth64,
+    /// The index and band width of each part.
+    pub contributors: Vec<(usize, Myth64)>,
+}
+
+impl T128 {
+    /// Stacks the `parts` and returns the nominal value and the worst-case and RSS band widths.
+    ///
+    /// ```rust
+    /// # use tolerance::{Myth64, T128};
+    /// let report = T128::stack_report(&[T128::with_sym(10.0, 0.15), T128::with_sym(5.0, 0.2)]);
+    /// assert_eq!(report.nominal, Myth64::from(15.0));
+    /// assert_eq!(report.worst_case, Myth64::from(0.7));
+    /// assert_eq!(report.rss, Myth64::from(0.5));
+    /// ```
+    pub fn stack_report(parts: &[T128]) -> StackReport {
+        let contributors: Vec<(usize, Myth64)> =
+            parts.iter().map(T128::width).enumerate().collect();
+        let squares: f64 = contributors
+            .iter()
+            .map(|(_, w)| (w.as_i64() as f64).powi(2))
+            .sum();
+        StackReport {
+            nominal: parts.iter().map(|p| p.value).sum(),
+            worst_case: contributors.iter().map(|(_, w)| w).sum(),
+            rss: Myth64(squares.sqrt().round() as i64),
+            contributors,
+        }
+    }
+
+    /// Returns the tolerance width left for a free part, so the chain of fixed `parts`
+    /// closes within the `target` width (worst-case). `None`, if nothing is left.
+    ///
+    /// ```rust
+    /// # use tolerance::{Myth32, T128};
+    /// let parts = [T128::with_sym(10.0, 0.05), T128::new(5.0, 0.1, 0.0)];
+    /// assert_eq!(T128::remaining_budget(&parts, Myth32::from(0.3)), Some(Myth32::from(0.1)));
+    /// ```
+    pub fn remaining_budget(parts: &[T128], target: Myth32) -> Option<Myth32> {
+        let used: Myth64 = parts.iter().map(T128::width).sum();
+        let remaining = Myth64::from(target) - used;
+        remaining
+            .is_positive()
+            .then(|| Myth32::try_from(remaining).expect("remaining is below target"))
+    }
+
+    /// Interpolates linearly between the points of a `table` of `(parameter, T128)` sorted by
+    /// the parameter. The `value`, `plus` and `minus` are blended separately.
+    ///
+    /// Returns `None`, if the table has less than two points or `at` is out of its range.
+    ///
+    /// ```rust
+    /// # use tolerance::T128;
+    /// let table = [(0.0, T128::with_sym(10.0, 0.1)), (1.0, T128::with_sym(20.0, 0.3))];
+    /// assert_eq!(T128::interpolate_table(&table, 0.5), Some(T128::with_sym(15.0, 0.2)));
+    /// assert_eq!(T128::interpolate_table(&table, 1.5), None);
+    /// ```
+    pub fn interpolate_table(table: &[(f64, T128)], at: f64) -> Option<T128> {
+        if table.len() < 2 {
+            return None;
+        }
+        let (&(p0, a), &(p1, b)) = table
+            .windows(2)
+            .map(|w| (&w[0], &w[1]))
+            .find(|((p0, _), (p1, _))| *p0 <= at && at <= *p1)?;
+        if at == p0 {
+            return Some(a);
+        }
+        let t = (at - p0) / (p1 - p0);
+        let blend = |a: i64, b: i64| (a as f64 + (b - a) as f64 * t).round() as i64;
+        Some(T128 {
+            value: Myth64(blend(a.value.as_i64(), b.value.as_i64())),
+            plus: Myth32(blend(a.plus.as_i64(), b.plus.as_i64()) as i32),
+            minus: Myth32(blend(a.minus.as_i64(), b.minus.as_i64()) as i32),
+        })
+    }
+
+    /// Stacks the `parts` statistically: the values are summed, the tolerances are combined
+    /// as root sum square `sqrt(Σ tol²)` of their [`half_width`](#method.half_width).
+    ///
+    /// The result is always symmetric, an asymmetric part only contributes its half width.
+    ///
+    /// ```rust
+    /// # use tolerance::{Myth32, T128};
+    /// let parts = [T128::with_sym(10.0, 0.1); 3];
+    /// assert_eq!(T128::rss_stack(&parts), T128::with_sym(30.0, Myth32::from(0.1732)));
+    /// assert_eq!(parts.iter().sum::<T128>(), T128::with_sym(30.0, 0.3));
+    /// ```
+    pub fn rss_stack(parts: &[T128]) -> T128 {
+        let squares: f64 = parts
+            .iter()
+            .map(|p| (p.half_width().as_i64() as f64).powi(2))
+            .sum();
+        T128::with_sym(
+            parts.iter().map(|p| p.value).sum::<Myth64>(),
+            Myth32(squares.sqrt().round() as i32),
+        )
+    }
+}
+
+/// Sums up named contributions to a stacked [`T128`] and records the width of each one.
+///
+/// ```rust
+/// # use tolerance::{Myth64, StackBuilder, T128};
+/// let (total, parts) = StackBuilder::new(100.0)
+///     .add("bracket", T128::with_sym(0.0, 0.05))
+///     .add("shim", T128::new(2.0, 0.1, 0.0))
+///     .build();
+/// assert_eq!(total, T128::new(102.0, 0.15, -0.05));
+/// assert_eq!(parts[1], ("shim".to_string(), Myth64::from(0.1)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct StackBuilder {
+    total: T128,
+    contributions: Vec<(String, Myth64)>,
+}
+
+impl StackBuilder {
+    /// Starts a stack at `nominal` without any tolerance.
+    pub fn new(nominal: impl Into<Myth64>) -> Self {
+        StackBuilder {
+            total: T128::from(nominal.into()),
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Adds the `part` named by `label`.
+    pub fn add(mut self, label: impl Into<String>, part: T128) -> Self {
+        self.total += part;
+        self.contributions.push((label.into(), part.width()));
+        self
+    }
+
+    /// Returns the stacked tolerance and the width of each contribution in order.
+    pub fn build(self) -> (T128, Vec<(String, Myth64)>) {
+        (self.total, self.contributions)
+    }
+}
+
+/// Orders a [`T128`] by the width of its tolerance band, equal widths by the `T128` itself.
+///
+/// Wrapped into [`Reverse`](std::cmp::Reverse) a `BinaryHeap` pops the tightest tolerance first.
+///
+/// ```rust
+/// # use tolerance::{ByWidth, T128};
+/// # use std::cmp::Reverse;
+/// # use std::collections::BinaryHeap;
+/// let mut heap = BinaryHeap::new();
+/// heap.push(Reverse(ByWidth(T128::with_sym(10.0, 0.2))));
+/// heap.push(Reverse(ByWidth(T128::new(50.0, 0.1, 0.0))));
+///
+/// assert_eq!(heap.pop(), Some(Reverse(ByWidth(T128::new(50.0, 0.1, 0.0)))));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByWidth(pub T128);
+
+impl ByWidth {
+    /// Returns the width of the tolerance band, the sort key.
+    pub fn key(&self) -> Myth64 {
+        self.0.width()
+    }
+}
+
+impl From<T128> for ByWidth {
+    fn from(t: T128) -> Self {
+        ByWidth(t)
+    }
+}
+
+impl PartialOrd for ByWidth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByWidth {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key()
+            .cmp(&other.key())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl From<T64> for T128 {
+    fn from(t: T64) -> Self {
+        T128 {
+            value: t.value.into(),
+            plus: t.plus.into(),
+            minus: t.minus.into(),
+        }
+    }
+}
+
+impl From<&T64> for T128 {
+    fn from(t: &T64) -> Self {
+        T128::from(*t)
+    }
+}
+
+impl PartialEq<T64> for T128 {
+    fn eq(&self, other: &T64) -> bool {
+        *self == T128::from(*other)
+    }
+}
+
+/// Compares like two `T128`s, the `T64` is widened.
+impl PartialOrd<T64> for T128 {
+    fn partial_cmp(&self, other: &T64) -> Option<Ordering> {
+        Some(self.cmp(&T128::from(*other)))
+    }
+}
+
 #[cfg(test)]
 mod should {
     use super::T128;
-    use crate::{error::ToleranceError, Myth32, Myth64};
+    use crate::{error::ToleranceError, Myth32, Myth64, Unit};
     use pretty_assertions::assert_eq;
     use std::convert::TryFrom;
+    use std::str::FromStr;
 
     #[test]
     fn convert_from_string() {
@@ -170,10 +460,10 @@ mod should {
         assert!(o <= T128::new(2_000, 5, -10));
 
         let simple: T128 = 30.0.into();
-        assert!(simple < 30.01.into());
-        assert!(simple > 29.0565.into());
-        assert!(simple <= 30.00.into());
-        assert!(simple >= 30.0.into());
+        assert!(simple < T128::from(30.01));
+        assert!(simple > T128::from(29.0565));
+        assert!(simple <= T128::from(30.00));
+        assert!(simple >= T128::from(30.0));
     }
 
     #[test]
@@ -228,9 +518,9 @@ mod should {
     fn subtract() {
         let minuend = T128::from((1000.0, 0.0, 0.0));
         let subtrahend = T128::from((300.0, 20.0, -10.0));
-        assert_eq!(minuend - subtrahend, (700.0, 10.0, -20.0).into());
+        assert_eq!(minuend - subtrahend, T128::from((700.0, 10.0, -20.0)));
         let minuend = T128::from((1000.0, 10.0, -30.0));
-        assert_eq!(minuend - subtrahend, (700.0, 20.0, -50.0).into());
+        assert_eq!(minuend - subtrahend, T128::from((700.0, 20.0, -50.0)));
     }
 
     #[test]
@@ -242,6 +532,753 @@ mod should {
         assert_eq!(basis + basis.invert(), T128::new(0.0, 1.5, -1.5));
     }
 
+    #[test]
+    fn neg_and_not_owned_and_borrowed() {
+        let t = T128::new(20.0, 1.0, -0.5);
+        assert_eq!(-&t, T128::new(-20.0, 0.5, -1.0));
+        assert_eq!(-t, -&t);
+        assert_eq!(!&t, T128::new(-20.0, 0.5, -1.0));
+        assert_eq!(!t, !&t);
+        assert_eq!(-t, !t);
+        assert_eq!(!t, t.invert());
+        assert_eq!((-t).upper_limit(), -t.lower_limit());
+        assert_eq!((-t).lower_limit(), -t.upper_limit());
+        assert_eq!(!!t, t);
+        assert_eq!(-(-t), t);
+    }
+
+    #[test]
+    fn construct_with_unit_tolerance() {
+        use crate::Unit;
+        assert_eq!(
+            T128::with_unit_tolerance(100.0, Unit::MY, 5),
+            Ok(T128::with_sym(100.0, 0.005))
+        );
+        assert_eq!(
+            T128::with_unit_tolerance(100.0, Unit::MM, -2),
+            Ok(T128::with_sym(100.0, 2.0))
+        );
+        assert_eq!(
+            T128::with_unit_tolerance(100.0, Unit::INCH, 1),
+            Ok(T128::with_sym(100.0, 25.4))
+        );
+        assert!(matches!(
+            T128::with_unit_tolerance(100.0, Unit::METER, 1_000),
+            Err(ToleranceError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn accept_measured_values() {
+        let spec = T128::new(10.0, 0.05, -0.02);
+        assert!(spec.accepts(10.0));
+        assert!(spec.accepts(10.05));
+        assert!(spec.accepts(9.98));
+        assert!(!spec.accepts(10.08));
+        assert!(!spec.accepts(9.9795));
+
+        assert_eq!(spec.reject_reason(10.01), None);
+        assert_eq!(
+            spec.reject_reason(10.08),
+            Some(String::from("0.03 mm over upper limit"))
+        );
+        assert_eq!(
+            spec.reject_reason(9.9795),
+            Some(String::from("0.0005 mm under lower limit"))
+        );
+    }
+
+    #[test]
+    fn combine_window_of_parts() {
+        let parts = [
+            T128::new(10.0, 0.1, -0.1),
+            T128::new(10.05, 0.1, -0.1),
+            T128::new(9.98, 0.05, -0.2),
+        ];
+        assert_eq!(
+            T128::combined_window(&parts),
+            Some((Myth64::from(9.95), Myth64::from(10.03)))
+        );
+        let disjoint = [T128::with_sym(10.0, 0.1), T128::with_sym(10.5, 0.1)];
+        assert_eq!(T128::combined_window(&disjoint), None);
+        assert_eq!(T128::combined_window(&[]), None);
+    }
+
+    #[test]
+    fn convert_gdt_triple() {
+        let t = T128::new(25.0, 0.04, 0.01);
+        assert_eq!((25.0, 0.04, 0.01), t.to_gdt_triple());
+        let (n, u, l) = t.to_gdt_triple();
+        assert_eq!(Ok(t), T128::from_gdt_triple(n, u, l));
+
+        assert!(matches!(
+            T128::from_gdt_triple(25.0, -0.01, 0.02),
+            Err(ToleranceError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn compute_width() {
+        let t = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(Myth64::from(0.25), t.width());
+        assert_eq!(Myth32::from(0.125), t.half_width());
+        let t = T128::new(100.0, 0.3, 0.1);
+        assert_eq!(Myth32::from(0.1), t.half_width());
+
+        let t = T128::with_sym(10.0, 0.06);
+        assert_eq!(1.0, t.cp(Myth32::from(0.02)));
+        assert_eq!(2.0, t.cp(Myth32::from(0.01)));
+    }
+
+    #[test]
+    fn allocate_budget() {
+        let parts = T128::allocate_budget(Myth32::from(1.0), &[1.0, 1.0, 1.0]);
+        assert_eq!(parts, vec![Myth32(3_333), Myth32(3_334), Myth32(3_333)]);
+        assert_eq!(Myth32::from(1.0), parts.iter().sum());
+
+        let parts = T128::allocate_budget(Myth32(1_000), &[1.0, 2.0, 3.0]);
+        assert_eq!(parts, vec![Myth32(167), Myth32(333), Myth32(500)]);
+        assert_eq!(Myth32(1_000), parts.iter().sum());
+
+        let parts = T128::allocate_budget(Myth32(-999), &[0.3, 0.0, 0.7]);
+        assert_eq!(parts, vec![Myth32(-300), Myth32(0), Myth32(-699)]);
+
+        assert!(T128::allocate_budget(Myth32(1_000), &[]).is_empty());
+    }
+
+    #[test]
+    fn chain_is_monotonic() {
+        let chain = [
+            T128::new(0.0, 0.1, -0.1),
+            T128::new(5.0, 0.2, -0.1),
+            T128::new(5.4, 0.0, -0.2),
+        ];
+        assert!(T128::chain_is_monotonic(&chain));
+        assert!(T128::chain_is_monotonic(&chain[..1]));
+        assert!(T128::chain_is_monotonic(&[]));
+
+        let chain = [
+            T128::new(0.0, 0.1, -0.1),
+            T128::new(5.0, 0.2, -0.1),
+            T128::new(5.3, 0.0, -0.2),
+        ];
+        assert!(!T128::chain_is_monotonic(&chain));
+    }
+
+    #[test]
+    fn parse_with_unit() {
+        assert_eq!(
+            T128::from_str("1in +0.001/-0.001"),
+            Ok(T128::with_sym(25.4, 0.0254))
+        );
+        assert_eq!(
+            T128::from_str("1in +0.1mm/-0.001"),
+            Ok(T128::new(25.4, 0.1, -0.0254))
+        );
+        assert_eq!(T128::from_str("2m 5um"), Ok(T128::with_sym(2000.0, 0.005)));
+        assert_eq!(
+            T128::from_str("-0.5cm +0.1/-2\u{b5}m"),
+            Ok(T128::new(-5.0, 1.0, -0.002))
+        );
+        assert_eq!(
+            T128::from_str("12 +0.1/-0.2"),
+            Ok(T128::new(12.0, 0.1, -0.2))
+        );
+        assert!(T128::from_str("12pc +0.1/-0.2").is_err());
+        assert!(T128::from_str("1km 1km").is_err());
+
+        assert_eq!(
+            T128::from_str("100 mm \u{b1}0.1"),
+            Ok(T128::with_sym(100.0, 0.1))
+        );
+        assert_eq!(
+            T128::from_str("1 in +0.001/-0.001"),
+            Ok(T128::with_sym(25.4, 0.0254))
+        );
+        assert_eq!(T128::from_str("1.5 in"), Ok(T128::from(38.1)));
+        assert!(T128::from_str("1 pc +0.1/-0.2").is_err());
+    }
+
+    #[test]
+    fn pop_tightest_first() {
+        use super::ByWidth;
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let tolerances = [
+            T128::with_sym(10.0, 0.2),
+            T128::new(50.0, 0.1, 0.0),
+            T128::new(5.0, 0.0, -0.4),
+            T128::with_sym(1.0, 0.05),
+        ];
+        assert_eq!(ByWidth(tolerances[0]).key(), Myth64::from(0.4));
+        let mut heap: BinaryHeap<_> = tolerances
+            .iter()
+            .map(|&t| Reverse(ByWidth::from(t)))
+            .collect();
+        let order: Vec<T128> = std::iter::from_fn(|| heap.pop().map(|Reverse(w)| w.0)).collect();
+        assert_eq!(
+            order,
+            vec![
+                T128::with_sym(1.0, 0.05),
+                T128::new(50.0, 0.1, 0.0),
+                T128::new(5.0, 0.0, -0.4),
+                T128::with_sym(10.0, 0.2),
+            ]
+        );
+    }
+
+    #[test]
+    fn asymmetry() {
+        assert_eq!(T128::with_sym(10.0, 0.1).asymmetry(), 0.0);
+        assert_eq!(T128::new(10.0, 0.2, 0.0).asymmetry(), 1.0);
+        assert_eq!(T128::new(10.0, 0.0, -0.2).asymmetry(), -1.0);
+        assert_eq!(T128::new(10.0, 0.3, 0.1).asymmetry(), 2.0);
+        assert_eq!(T128::new(10.0, 0.1, -0.3).asymmetry(), -0.5);
+        assert_eq!(T128::from(10.0).asymmetry(), 0.0);
+    }
+
+    #[test]
+    fn kind() {
+        use crate::T64;
+        let t = T128::with_sym(1.0, 0.1);
+        assert_eq!(t.kind(), "T128");
+        assert_eq!(t.byte_len(), 16);
+        assert_eq!(t.to_le_bytes().len(), t.byte_len());
+        assert_eq!(T64::ZERO.kind(), "T64");
+        assert_eq!(T64::ZERO.byte_len(), 8);
+    }
+
+    #[test]
+    fn from_limits() {
+        assert_eq!(T128::from_limits(9.8, 10.2), Ok(T128::with_sym(10.0, 0.2)));
+        assert_eq!(T128::from_limits(5.0, 5.0), Ok(T128::from(5.0)));
+        // raw values in 0.1μ
+        assert_eq!(T128::from_limits(-3, 0), Ok(T128::new(-2, 2, -1)));
+        assert!(T128::from_limits(10.2, 9.8).is_err());
+    }
+
+    #[test]
+    fn tighter_looser() {
+        let a = T128::new(10.0, 0.1, -0.1);
+        let b = T128::new(12.0, 0.3, 0.0);
+        assert_eq!(a.tighter(b), a);
+        assert_eq!(b.tighter(a), a);
+        assert_eq!(a.looser(b), b);
+        assert_eq!(b.looser(a), b);
+
+        let c = T128::new(8.0, 0.0, -0.2);
+        assert_eq!(a.tighter(c), c);
+        assert_eq!(c.tighter(a), c);
+        assert_eq!(a.looser(c), a);
+        assert_eq!(c.looser(a), a);
+    }
+
+    #[test]
+    fn rereference() {
+        let t = T128::new(30.0, 0.2, -0.1);
+        let moved = t.shift_keeping_deviations(-2.5);
+        assert_eq!(moved, T128::new(27.5, 0.2, -0.1));
+        assert_eq!(moved.upper_limit(), t.upper_limit() - Myth64::from(2.5));
+
+        let kept = t.shift_keeping_limits(30.1).unwrap();
+        assert_eq!(kept, T128::new(30.1, 0.1, -0.2));
+        assert_eq!(kept.upper_limit(), t.upper_limit());
+        assert_eq!(kept.lower_limit(), t.lower_limit());
+
+        let (old, new) = (Myth64::from(5.0), Myth64::from(12.0));
+        let r = t.rereference(old, new);
+        assert_eq!(r.lower_limit(), t.lower_limit() + old - new);
+        assert_eq!(r.upper_limit(), t.upper_limit() + old - new);
+        assert_eq!(r.rereference(new, old), t);
+    }
+
+    #[test]
+    fn try_from_map() {
+        use std::collections::HashMap;
+        let map = |fields: &[(&str, f64)]| -> HashMap<String, f64> {
+            fields.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+        };
+        let full = map(&[("value", 10.0), ("plus", 0.05), ("minus", -0.2)]);
+        assert_eq!(T128::try_from(&full), Ok(T128::new(10.0, 0.05, -0.2)));
+        let aliases = map(&[("v", 10.0), ("p", 0.05), ("m", -0.2)]);
+        assert_eq!(T128::try_from(&aliases), Ok(T128::new(10.0, 0.05, -0.2)));
+        let partial = map(&[("value", 10.0), ("p", 0.1)]);
+        assert_eq!(T128::try_from(&partial), Ok(T128::with_sym(10.0, 0.1)));
+        let only_value = map(&[("v", 10.0)]);
+        assert_eq!(T128::try_from(&only_value), Ok(T128::from(10.0)));
+
+        assert!(T128::try_from(&map(&[("plus", 0.1)])).is_err());
+        assert!(T128::try_from(&map(&[("value", 1.0), ("v", 1.0)])).is_err());
+        assert!(T128::try_from(&map(&[("value", 1.0), ("width", 1.0)])).is_err());
+        assert!(T128::try_from(&map(&[("value", f64::NAN)])).is_err());
+        assert!(T128::try_from(&map(&[("value", 1.0), ("plus", 1e12)])).is_err());
+    }
+
+    #[test]
+    fn to_aligned_string() {
+        let rows = [
+            T128::with_sym(100.0, 0.05),
+            T128::new(12.5, 0.2, -0.1),
+            T128::new(-3.0, 0.0, -0.02),
+            T128::new(7.25, 0.03, 0.01),
+        ];
+        let lines: Vec<String> = rows.iter().map(|t| t.to_aligned_string(8, 2)).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "  100.00 ±0.05",
+                "   12.50 +0.20/-0.10",
+                "   -3.00 +0.00/-0.02",
+                "    7.25 +0.03/+0.01",
+            ]
+        );
+        assert_eq!(
+            T128::with_sym(1.0, 0.0001).to_aligned_string(0, 9),
+            "1.0000 ±0.0001"
+        );
+    }
+
+    #[test]
+    fn with_process_shift() {
+        let t = T128::new(10.0, 0.3, -0.1);
+        assert_eq!(t.with_process_shift(0.5), T128::new(10.1, 0.3, -0.1));
+        assert_eq!(t.with_process_shift(-1.0), T128::new(9.8, 0.3, -0.1));
+        assert_eq!(t.with_process_shift(-3.0), t.with_process_shift(-1.0));
+        assert_eq!(t.with_process_shift(0.0), t);
+        assert_eq!(
+            T128::new(1.0, 3, 0).with_process_shift(0.5),
+            T128::new(1.0001, 3, 0)
+        );
+    }
+
+    #[test]
+    fn stack_report() {
+        let parts = [
+            T128::new(25.0, 0.1, -0.1),
+            T128::new(-10.0, 0.05, 0.0),
+            T128::new(40.0, 0.0, -0.3),
+        ];
+        let report = T128::stack_report(&parts);
+        assert_eq!(report.nominal, Myth64::from(55.0));
+        assert_eq!(report.worst_case, Myth64::from(0.55));
+        assert_eq!(
+            report.worst_case,
+            parts.iter().map(|p| p.width()).sum::<Myth64>()
+        );
+        // sqrt(0.2² + 0.05² + 0.3²)
+        assert_eq!(report.rss, Myth64(3_640));
+        assert!(report.rss < report.worst_case);
+        assert_eq!(
+            report.contributors,
+            vec![
+                (0, Myth64::from(0.2)),
+                (1, Myth64::from(0.05)),
+                (2, Myth64::from(0.3))
+            ]
+        );
+        assert_eq!(T128::stack_report(&[]).rss, Myth64::ZERO);
+    }
+
+    #[test]
+    fn stack_builder() {
+        use super::StackBuilder;
+        let bracket = T128::with_sym(12.0, 0.05);
+        let shim = T128::new(1.5, 0.02, -0.01);
+        let bolt = !T128::new(4.0, 0.1, 0.0);
+        let (total, parts) = StackBuilder::new(0.0)
+            .add("bracket", bracket)
+            .add("shim", shim)
+            .add(String::from("bolt"), bolt)
+            .build();
+        assert_eq!(total, bracket + shim + bolt);
+        assert_eq!(
+            parts,
+            vec![
+                ("bracket".to_string(), Myth64::from(0.1)),
+                ("shim".to_string(), Myth64::from(0.03)),
+                ("bolt".to_string(), Myth64::from(0.1)),
+            ]
+        );
+        assert_eq!(StackBuilder::new(5.0).build(), (T128::from(5.0), vec![]));
+    }
+
+    #[test]
+    fn clamp_value() {
+        let t = T128::new(10.0, 0.2, -0.1);
+        assert_eq!(t.clamp_value(10.5, 12.0), T128::new(10.5, 0.2, -0.1));
+        assert_eq!(t.clamp_value(5.0, 8.0), T128::new(8.0, 0.2, -0.1));
+        assert_eq!(t.clamp_value(5.0, 12.0), t);
+    }
+
+    #[test]
+    fn midpoint_and_span() {
+        let t = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(t.midpoint(), Myth64::from(99.925));
+        assert_eq!(t.span(), Myth64::from(0.25));
+        let sym = T128::with_sym(20.0, 0.1);
+        assert_eq!(sym.midpoint(), sym.value);
+        assert_eq!(sym.span(), Myth64::from(0.2));
+    }
+
+    #[test]
+    fn round_to_standard() {
+        let computed = T128::new(10.0, Myth32(123), Myth32(-31));
+        let rounded = computed.round_to_standard();
+        assert_eq!(rounded, T128::new(10.0, 0.014, -0.004));
+        assert!(rounded.enfold(computed));
+        let standard = T128::new(10.0, 0.01, -0.005);
+        assert_eq!(standard.round_to_standard(), standard);
+        let coarse = computed.round_to_increments(&[Unit::STANDARD_INCREMENTS[0]]);
+        assert_eq!(coarse, T128::new(10.0, 0.02, -0.01));
+        assert!(coarse.enfold(computed));
+        assert_eq!(computed.round_to_increments(&[]), computed);
+    }
+
+    #[test]
+    fn contains() {
+        let t = T128::new(20.0, 0.1, -0.05);
+        assert!(t.contains(20.02));
+        assert!(t.contains(t.lower_limit()));
+        assert!(t.contains(t.upper_limit()));
+        assert!(!t.contains(Myth64(199_499)));
+        assert!(!t.contains(Myth64(201_001)));
+        let shifted = T128::new(20.0, 0.3, 0.1);
+        assert!(!shifted.contains(20.0));
+        assert!(shifted.contains(20.1));
+        assert!(shifted.contains(20.2));
+        assert!(shifted.contains(20.3));
+        assert!(!shifted.contains(20.4));
+    }
+
+    #[test]
+    fn rss_stack() {
+        let parts = [T128::with_sym(10.0, 0.1); 3];
+        assert_eq!(T128::rss_stack(&parts), T128::with_sym(30.0, Myth32(1_732)));
+        let mixed = [T128::new(10.0, 0.3, 0.0), T128::with_sym(5.0, 0.2)];
+        assert_eq!(T128::rss_stack(&mixed), T128::with_sym(15.0, 0.25));
+        assert_eq!(T128::rss_stack(&[]), T128::ZERO);
+    }
+
+    #[test]
+    fn simplify() {
+        let near = T128::new(10.0, Myth32(1_000), Myth32(-1_001));
+        assert_eq!(near.simplify(Myth32(1)), T128::with_sym(10.0, 0.1));
+        assert_eq!(near.simplify(Myth32::ZERO), near);
+        let asym = T128::new(10.0, 0.1, -0.2);
+        assert_eq!(asym.simplify(Myth32(1)), asym);
+
+        let below = T128::new(10.0, -0.1, -0.2);
+        assert_eq!(below.simplify(Myth32::from(0.5)), below);
+        let wide = T128::new(10.0, 1.7, Myth32(-16_999));
+        assert_eq!(wide.simplify(Myth32(1)), T128::with_sym(10.0, 1.7));
+    }
+
+    #[test]
+    fn compare_with_t64() {
+        use crate::T64;
+        let small = T64::new(10.0, 0.1, -0.2);
+        assert_eq!(T128::new(10.0, 0.1, -0.2), small);
+        assert_ne!(T128::new(10.0, 0.1, -0.1), small);
+        assert!(T128::new(10.5, 0.1, -0.2) > small);
+        assert!(T128::new(10.0, 0.1, -0.3) < small);
+        assert!(T128::new(10.0, 0.2, -0.2) > small);
+        assert_eq!(
+            T128::from(small).partial_cmp(&small),
+            Some(std::cmp::Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let outer = T128::new(10.0, 0.2, -0.2);
+        let inner = T128::new(10.0, 0.1, 0.0);
+        assert_eq!(
+            outer.intersection(&inner),
+            Some(T128::with_sym(10.05, 0.05))
+        );
+        assert_eq!(inner.intersection(&outer), outer.intersection(&inner));
+        let partial = T128::new(10.3, 0.0, -0.2);
+        assert_eq!(
+            outer.intersection(&partial),
+            Some(T128::with_sym(10.15, 0.05))
+        );
+        let touching = T128::new(10.3, 0.0, -0.1);
+        assert_eq!(outer.intersection(&touching), Some(T128::from(10.2)));
+        let disjoint = T128::with_sym(11.0, 0.1);
+        assert_eq!(outer.intersection(&disjoint), None);
+    }
+
+    #[test]
+    fn hull() {
+        let a = T128::new(10.0, 0.2, -0.1);
+        for b in [
+            T128::with_sym(10.2, 0.1),
+            T128::new(10.0, 0.1, 0.0),
+            T128::with_sym(11.0, 0.1),
+        ] {
+            let hull = a.hull(&b);
+            assert!(hull.enfold(a) && hull.enfold(b));
+            assert_eq!(hull, b.hull(&a));
+        }
+        assert_eq!(
+            a.hull(&T128::with_sym(11.0, 0.1)),
+            T128::with_sym(10.5, 0.6)
+        );
+        assert_eq!(a.hull(&a), T128::with_sym(10.05, 0.15));
+    }
+
+    #[test]
+    fn to_dual_string() {
+        let t = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(
+            t.to_dual_string(Unit::MM, Unit::INCH),
+            "100.0 mm [3.937 in] +0.05/-0.2"
+        );
+        let t = T128::new(25.4, 0.0254, 0.0);
+        assert_eq!(
+            t.to_dual_string(Unit::INCH, Unit::MM),
+            "1.0 in [25.4 mm] +0.001/-0.0"
+        );
+        let t = T128::new(-12.0, -0.1, -0.3);
+        assert_eq!(
+            t.to_dual_string(Unit::CM, Unit::MM),
+            "-1.2 cm [-12.0 mm] -0.01/-0.03"
+        );
+    }
+
+    #[test]
+    fn widen_t64() {
+        use crate::T64;
+        let small = T64::new(-123.4567, 0.25, -3.0);
+        let wide = T128::from(small);
+        assert_eq!(wide.value, Myth64::from(small.value));
+        assert_eq!(wide.plus, Myth32::from(small.plus));
+        assert_eq!(wide.minus, Myth32::from(small.minus));
+        assert_eq!(T128::from(&small), wide);
+        assert_eq!(T128::from(T64::ZERO), T128::ZERO);
+    }
+
+    #[test]
+    fn abs_and_signum() {
+        let positive = T128::new(10.0, 0.1, -0.2);
+        assert_eq!(positive.abs(), positive);
+        assert_eq!(positive.signum(), Myth64(1));
+        let negative = T128::new(-10.0, 0.1, -0.2);
+        assert_eq!(negative.abs(), T128::new(10.0, 0.2, -0.1));
+        assert_eq!(negative.abs(), negative.invert());
+        assert_eq!(negative.signum(), Myth64(-1));
+        let zero = T128::new(0.0, 0.1, -0.2);
+        assert_eq!(zero.abs(), zero);
+        assert_eq!(zero.signum(), Myth64::ZERO);
+    }
+
+    #[test]
+    fn remaining_budget() {
+        let parts = [T128::with_sym(10.0, 0.05), T128::new(5.0, 0.1, 0.0)];
+        assert_eq!(
+            T128::remaining_budget(&parts, Myth32::from(0.25)),
+            Some(Myth32::from(0.05))
+        );
+        assert_eq!(T128::remaining_budget(&parts, Myth32::from(0.2)), None);
+        assert_eq!(T128::remaining_budget(&parts, Myth32::from(0.1)), None);
+        assert_eq!(
+            T128::remaining_budget(&[], Myth32::from(0.1)),
+            Some(Myth32::from(0.1))
+        );
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!(
+            T128::new(100.0, 0.2, -0.2) / 4,
+            T128::new(25.0, 0.05, -0.05)
+        );
+        assert_eq!(
+            T128::new(10.0, 0.1, 0.0) / 3u8,
+            T128::new(Myth64(33_333), Myth32(333), Myth32::ZERO)
+        );
+        let t = T128::new(10.0, 0.1, -0.2);
+        let back = t / 7i32 * 7i32;
+        assert!((back.value - t.value).abs() < Myth64(7));
+        assert!((back.plus - t.plus).abs() < Myth32(7));
+        assert!((back.minus - t.minus).abs() < Myth32(7));
+    }
+
+    #[test]
+    fn contains_band_strict() {
+        let spec = T128::new(10.0, 0.2, -0.2);
+        let margin = T128::with_sym(10.0, 0.1);
+        assert!(spec.enfold(margin));
+        assert!(spec.contains_band_strict(margin));
+        let shared = T128::new(10.0, 0.2, 0.0);
+        assert!(spec.enfold(shared));
+        assert!(!spec.contains_band_strict(shared));
+        assert!(spec.enfold(spec));
+        assert!(!spec.contains_band_strict(spec));
+    }
+
+    #[test]
+    fn scale_tolerance() {
+        let t = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(t.scale_tolerance(2.0), T128::new(100.0, 0.10, -0.4));
+        assert_eq!(t.scale_tolerance(0.5), T128::new(100.0, 0.025, -0.1));
+        assert_eq!(t.scale_tolerance(0.0), T128::from(100.0));
+        assert_eq!(
+            T128::new(10.0, 0.3, 0.1).scale_tolerance(1.0 / 3.0),
+            T128::new(10.0, 0.1, Myth32(333))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_tolerance_negative() {
+        let _ = T128::new(100.0, 0.05, -0.2).scale_tolerance(-1.0);
+    }
+
+    #[test]
+    fn to_symmetric() {
+        let t = T128::new(100.0, 0.3, -0.1);
+        assert!(!t.is_symmetric());
+        let sym = t.to_symmetric();
+        assert!(sym.is_symmetric());
+        assert_eq!(sym, T128::with_sym(100.1, 0.2));
+        assert_eq!(sym.lower_limit(), t.lower_limit());
+        assert_eq!(sym.upper_limit(), t.upper_limit());
+        let odd = T128::new(10.0, Myth32(3), Myth32::ZERO).to_symmetric();
+        assert!(odd.enfold(T128::new(10.0, Myth32(3), Myth32::ZERO)));
+        assert_eq!(odd.width(), Myth64(4));
+        assert!(T128::with_sym(5.0, 0.1).is_symmetric());
+    }
+
+    #[test]
+    fn unilateral() {
+        let upper = T128::upper_only(10.0, 0.2);
+        assert_eq!(upper, T128::new(10.0, 0.2, 0.0));
+        assert_eq!(upper.lower_limit(), Myth64::from(10.0));
+        assert_eq!(upper.upper_limit(), Myth64::from(10.2));
+        assert!(upper.is_unilateral());
+        let lower = T128::lower_only(10.0, -0.2);
+        assert_eq!(lower.lower_limit(), Myth64::from(9.8));
+        assert_eq!(lower.upper_limit(), Myth64::from(10.0));
+        assert!(lower.is_unilateral());
+        assert!(!T128::with_sym(10.0, 0.2).is_unilateral());
+        assert!(!T128::from(10.0).is_unilateral());
+    }
+
+    #[test]
+    #[should_panic]
+    fn upper_only_negative() {
+        let _ = T128::upper_only(10.0, -0.2);
+    }
+
+    #[test]
+    fn parse_parentheses() {
+        assert_eq!("100.0 (\u{b1}0.1)".parse(), Ok(T128::with_sym(100.0, 0.1)));
+        assert_eq!("100.0 (+0.2/-0.1)".parse(), Ok(T128::new(100.0, 0.2, -0.1)));
+        assert_eq!("100.0(+0.2 -0.1) ".parse(), Ok(T128::new(100.0, 0.2, -0.1)));
+        assert_eq!("100.0 \u{b1}0.1".parse(), Ok(T128::with_sym(100.0, 0.1)));
+        assert!("100.0 (+0.2/-0.1".parse::<T128>().is_err());
+        assert!("100.0 +0.2/-0.1)".parse::<T128>().is_err());
+        assert!("100.0 )+0.2/-0.1(".parse::<T128>().is_err());
+        assert!("(100.0) +0.2/-0.1".parse::<T128>().is_err());
+        assert!("100 \u{b1}-0.1".parse::<T128>().is_err());
+        assert!("100 (\u{b1} +0.1)".parse::<T128>().is_err());
+        assert!("100 +/--0.1".parse::<T128>().is_err());
+        assert!("100 +--0.1".parse::<T128>().is_err());
+    }
+
+    #[test]
+    fn expected_value() {
+        let t = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(t.expected_value(), Myth64::from(99.925));
+        assert_eq!(t.expected_value_normal(), Myth64::from(100.0));
+        let sym = T128::with_sym(100.0, 0.1);
+        assert_eq!(sym.expected_value(), sym.expected_value_normal());
+    }
+
+    #[test]
+    fn with_safety_factor() {
+        let t = T128::new(10.0, 0.2, -0.08);
+        let safe = T128::with_safety_factor(t, 1.25);
+        assert_eq!(safe, T128::new(10.0, 0.25, -0.1));
+        assert_eq!(safe.value, t.value);
+        assert!(safe.enfold(t));
+        assert_eq!(T128::with_safety_factor(t, -1.0), T128::from(10.0));
+        let huge = T128::with_safety_factor(t, 1e30);
+        assert_eq!((huge.plus, huge.minus), (Myth32::MAX, Myth32::MIN));
+    }
+
+    #[test]
+    fn round_value() {
+        let tol = |v: i64| T128::new(Myth64(v), 0.05, -0.2);
+        assert_eq!(tol(1_234_567).round_value(Unit::MY), tol(1_234_570));
+        assert_eq!(tol(1_234_567).round_value(Unit::CM), tol(1_200_000));
+        assert_eq!(tol(9_999_000).round_value(Unit::MM), tol(10_000_000));
+        assert_eq!(tol(-4_993).round_value(Unit::MM), tol(0));
+        assert_eq!(tol(-4_993).round_value(Unit::MY), tol(-4_990));
+        assert_eq!(tol(-5_000).round_value(Unit::MM), tol(-10_000));
+        assert_eq!(tol(1_234_567).floor_value(Unit::MM), tol(1_230_000));
+        assert_eq!(tol(-4_993).floor_value(Unit::MM), tol(-10_000));
+        assert_eq!(tol(1_234_567).ceil_value(Unit::MM), tol(1_240_000));
+        assert_eq!(tol(-4_993).ceil_value(Unit::MM), tol(0));
+    }
+
+    #[test]
+    fn limits() {
+        let (lower, upper) = T128::new(100.0, 0.05, -0.2).limits();
+        assert_eq!(lower.value, Myth64::from(99.8));
+        assert_eq!(upper.value, Myth64::from(100.05));
+        for limit in [lower, upper] {
+            assert_eq!((limit.plus, limit.minus), (Myth32::ZERO, Myth32::ZERO));
+        }
+    }
+
+    #[test]
+    fn interpolate_table() {
+        let table = [
+            (0.0, T128::new(10.0, 0.1, -0.1)),
+            (2.0, T128::new(20.0, 0.3, 0.1)),
+            (3.0, T128::new(15.0, 0.1, -0.1)),
+        ];
+        assert_eq!(T128::interpolate_table(&table, 0.0), Some(table[0].1));
+        assert_eq!(T128::interpolate_table(&table, 2.0), Some(table[1].1));
+        assert_eq!(T128::interpolate_table(&table, 3.0), Some(table[2].1));
+        assert_eq!(
+            T128::interpolate_table(&table, 0.5),
+            Some(T128::new(12.5, 0.15, -0.05))
+        );
+        assert_eq!(
+            T128::interpolate_table(&table, 2.5),
+            Some(T128::new(17.5, 0.2, 0.0))
+        );
+        assert_eq!(T128::interpolate_table(&table, -0.1), None);
+        assert_eq!(T128::interpolate_table(&table, 3.1), None);
+        assert_eq!(T128::interpolate_table(&table, f64::NAN), None);
+        assert_eq!(T128::interpolate_table(&table[..1], 0.0), None);
+    }
+
+    #[test]
+    fn deviations_mm() {
+        assert_eq!(T128::new(100.0, 0.05, -0.2).deviations_mm(), (0.05, -0.2));
+        assert_eq!(T128::new(100.0, 0.3, 0.1).deviations_mm(), (0.3, 0.1));
+        assert_eq!(T128::new(100.0, -0.1, -0.3).deviations_mm(), (-0.1, -0.3));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn embrace_like_enfold() {
+        let outer = T128::new(10.0, 0.2, -0.2);
+        for other in [
+            T128::new(10.0, 0.1, -0.2),
+            outer,
+            T128::new(10.1, 0.2, -0.2),
+        ] {
+            assert_eq!(outer.embrace(other), outer.enfold(other));
+            assert_eq!(other.embrace(outer), other.enfold(outer));
+        }
+    }
+
     #[test]
     fn error() {
         let tol = T128::try_from("nil");
@@ -264,7 +1301,17 @@ mod should {
         use crate::*;
         use pretty_assertions::assert_eq;
         use serde::{Deserialize, Serialize};
-        use serde_test::{assert_de_tokens, assert_tokens, Token};
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+        #[test]
+        fn postcard_round_trip() {
+            let t = T128::new(-12.5, 0.25, -0.0001);
+            let bytes = postcard::to_allocvec(&t).unwrap();
+            assert_eq!(postcard::from_bytes::<T128>(&bytes), Ok(t));
+            let m = t.value;
+            let bytes = postcard::to_allocvec(&m).unwrap();
+            assert_eq!(postcard::from_bytes(&bytes), Ok(m));
+        }
 
         #[test]
         fn serialize_std() {
@@ -281,6 +1328,53 @@ mod should {
             );
         }
 
+        #[cfg(feature = "serde_json")]
+        #[test]
+        fn try_from_json_value() {
+            use serde_json::json;
+            let t = T128::new(100.0, 0.05, -0.2);
+            assert_eq!(T128::try_from(&json!("100.0 +0.05/-0.2")), Ok(t));
+            assert_eq!(T128::try_from(&json!(1_000_000)), Ok(T128::from(100.0)));
+            assert_eq!(T128::try_from(&json!(100.0)), Ok(T128::from(100.0)));
+            assert_eq!(T128::try_from(&json!([1_000_000, 500, -2000])), Ok(t));
+            assert_eq!(
+                T128::try_from(&json!({"value": 1_000_000, "plus": 500, "minus": -2000})),
+                Ok(t)
+            );
+            assert_eq!(
+                T128::try_from(&json!({"v": "100.0", "p": "0.05"})),
+                Ok(T128::with_sym(100.0, 0.05))
+            );
+            assert!(T128::try_from(&json!({"plus": 500})).is_err());
+            assert!(T128::try_from(&json!(true)).is_err());
+        }
+
+        #[test]
+        fn serialize_to_drawing_strings() {
+            #[derive(Serialize)]
+            struct T2 {
+                #[serde(serialize_with = "T128::into_slash_string")]
+                slash: T128,
+                #[serde(serialize_with = "T128::into_space_string")]
+                space: T128,
+                #[serde(serialize_with = "T128::into_asym_string")]
+                asym: T128,
+            }
+            let t = T2 {
+                slash: T128::new(25.0, 0.021, 0.0),
+                space: T128::new(12.5, 0.2, 0.1),
+                asym: T128::with_sym(-3.0, 0.05),
+            };
+            assert_eq!(
+                r#"{"slash":"25.0/+0.021/-0.0","space":"12.5 +0.2 +0.1","asym":"-3.0 +0.05/-0.05"}"#,
+                serde_json::to_string(&t).unwrap()
+            );
+            // all of them are parsable again
+            assert_eq!(T128::try_from("25.0/+0.021/-0.0"), Ok(t.slash));
+            assert_eq!(T128::try_from("12.5 +0.2 +0.1"), Ok(t.space));
+            assert_eq!(T128::try_from("-3.0 +0.05/-0.05"), Ok(t.asym));
+        }
+
         #[test]
         fn serialize_to_tol_string() {
             #[derive(Serialize)]
@@ -353,7 +1447,7 @@ mod should {
                 serde_json::to_string(&t).unwrap()
             );
             assert_tokens(
-                &t,
+                &t.readable(),
                 &[
                     Token::Struct { name: "T2", len: 1 },
                     Token::Str("width"),
@@ -375,7 +1469,7 @@ mod should {
             let m = T128::from(12456.832);
 
             assert_tokens(
-                &m,
+                &m.readable(),
                 &[
                     Token::Struct {
                         name: "T128",
@@ -400,7 +1494,7 @@ mod should {
             let tol = T128::from(1230000);
             // Full
             assert_tokens(
-                &tol,
+                &tol.readable(),
                 &[
                     Token::Struct {
                         name: "T128",
@@ -420,7 +1514,7 @@ mod should {
             );
             // aliasse
             assert_de_tokens(
-                &tol,
+                &tol.readable(),
                 &[
                     Token::Struct {
                         name: "T128",
@@ -440,7 +1534,7 @@ mod should {
             );
             // defaults
             assert_de_tokens(
-                &Some(tol),
+                &Some(tol).readable(),
                 &[
                     Token::Some,
                     Token::Struct {
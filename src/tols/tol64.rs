@@ -2,11 +2,11 @@ use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::iter::Sum;
-use std::ops::{Add, AddAssign, Mul, Neg, Not, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Not, Sub, SubAssign};
 use std::str::FromStr;
 
 use crate::error::ToleranceError::ParseError;
-use crate::{error, Myth16, Myth32};
+use crate::{error, Myth16, Myth32, T128};
 
 /// # 64bit tolerance-type
 ///
@@ -26,11 +26,14 @@ use crate::{error, Myth16, Myth32};
 ///
 /// The `plus` and `minus` tolerances are in the same scale unit as the `value`.
 /// `plus` is signed positive (`+`) and `minus` is signed negative (`-`).
+///
+/// The layout is `#[repr(C)]`: `value`, `plus` and `minus` in 8 bytes without padding.
 #[cfg_attr(
     feature = "serde",
     doc = include_str!("serde.md")
 )]
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
 #[must_use]
 pub struct T64 {
     #[cfg_attr(feature = "serde", doc = "In deserialization `value` or `v` is used.")]
@@ -43,13 +46,28 @@ pub struct T64 {
 
 super::tolerance_body!(T64, Myth32, Myth16);
 super::multiply_tolerance!(T64, u64, u32, u16, u8, i64, i32);
+super::divide_tolerance!(T64, u64, u32, u16, u8, i64, i32);
 #[cfg(feature = "serde")]
 super::de_serde_tol!(T64, Myth32, Myth16);
 
+/// Narrows a `T128`, fails with an `Overflow` if a part does not fit.
+impl TryFrom<T128> for T64 {
+    type Error = error::ToleranceError;
+
+    fn try_from(t: T128) -> Result<Self, Self::Error> {
+        let overflow = |_| error::ToleranceError::Overflow(format!("{t} does not fit into a T64."));
+        Ok(T64 {
+            value: Myth32::try_from(t.value).map_err(overflow)?,
+            plus: Myth16::try_from(t.plus).map_err(overflow)?,
+            minus: Myth16::try_from(t.minus).map_err(overflow)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod should {
     use super::T64;
-    use crate::error::ToleranceError;
+    use crate::{error::ToleranceError, Myth16, Myth32, Unit};
     use pretty_assertions::assert_eq;
     use std::convert::TryFrom;
 
@@ -140,6 +158,548 @@ mod should {
         assert_eq!(basis + basis.invert(), T64::new(0.0, 1.5, -1.5));
     }
 
+    #[test]
+    fn neg_and_not_owned_and_borrowed() {
+        let t = T64::new(20.0, 1.0, -0.5);
+        assert_eq!(-&t, T64::new(-20.0, 0.5, -1.0));
+        assert_eq!(-t, -&t);
+        assert_eq!(!&t, T64::new(-20.0, 0.5, -1.0));
+        assert_eq!(!t, !&t);
+        assert_eq!(-t, !t);
+        assert_eq!(!t, t.invert());
+        assert_eq!((-t).upper_limit(), -t.lower_limit());
+        assert_eq!((-t).lower_limit(), -t.upper_limit());
+        assert_eq!(!!t, t);
+        assert_eq!(-(-t), t);
+    }
+
+    #[test]
+    fn construct_with_unit_tolerance() {
+        use crate::Unit;
+        assert_eq!(
+            T64::with_unit_tolerance(10.0, Unit::MY, 5),
+            Ok(T64::with_sym(10.0, 0.005))
+        );
+        assert!(matches!(
+            T64::with_unit_tolerance(10.0, Unit::MM, 4),
+            Err(ToleranceError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn accept_measured_values() {
+        let spec = T64::new(2.0, 0.1, 0.05);
+        assert!(spec.accepts(2.08));
+        assert!(!spec.accepts(2.0));
+        assert_eq!(
+            spec.reject_reason(2.0),
+            Some(String::from("0.05 mm under lower limit"))
+        );
+    }
+
+    #[test]
+    fn combine_window_of_parts() {
+        use crate::Myth32;
+        let parts = [T64::with_sym(5.0, 0.2), T64::new(5.1, 0.2, 0.0)];
+        assert_eq!(
+            T64::combined_window(&parts),
+            Some((Myth32::from(5.1), Myth32::from(5.2)))
+        );
+        let disjoint = [T64::with_sym(5.0, 0.2), T64::with_sym(6.0, 0.2)];
+        assert_eq!(T64::combined_window(&disjoint), None);
+    }
+
+    #[test]
+    fn convert_gdt_triple() {
+        let t = T64::new(5.0, 0.2, -0.1);
+        let (n, u, l) = t.to_gdt_triple();
+        assert_eq!(Ok(t), T64::from_gdt_triple(n, u, l));
+        assert!(T64::from_gdt_triple(n, l, u).is_err());
+        assert!(matches!(
+            T64::from_gdt_triple(10.0, 5.0, 0.0),
+            Err(ToleranceError::Overflow(_))
+        ));
+        assert!(matches!(
+            T64::from_gdt_triple(10.0, 0.0, -5.0),
+            Err(ToleranceError::Overflow(_))
+        ));
+        assert!(matches!(
+            T64::from_gdt_triple(300_000.0, 0.1, 0.0),
+            Err(ToleranceError::Overflow(_))
+        ));
+        assert!(matches!(
+            T64::from_gdt_triple(f64::NAN, 0.1, 0.0),
+            Err(ToleranceError::Validation(_))
+        ));
+        assert!(matches!(
+            T64::from_gdt_triple(10.0, f64::INFINITY, 0.0),
+            Err(ToleranceError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn compute_width() {
+        use crate::Myth16;
+        let t = T64::new(10.0, 0.05, -0.2);
+        assert_eq!(Myth32::from(0.25), t.width());
+        assert_eq!(Myth16::from(0.125), t.half_width());
+
+        let wide = T64::with_sym(10.0, 1.7);
+        assert_eq!(wide.width(), Myth32::from(3.4));
+        assert_eq!(wide.half_width(), Myth16::from(1.7));
+        assert_eq!(wide.cp(Myth16::from(0.1)), 34.0 / 6.0);
+        let widest = T64::new(0.0, Myth16::MAX, Myth16::MIN);
+        assert_eq!(widest.half_width(), Myth16::MAX);
+        assert_eq!(widest.width(), Myth32(65_535));
+    }
+
+    #[test]
+    fn asymmetry() {
+        assert_eq!(T64::with_sym(10.0, 0.1).asymmetry(), 0.0);
+        assert_eq!(T64::new(10.0, 0.2, 0.0).asymmetry(), 1.0);
+        assert_eq!(T64::new(10.0, 0.0, -0.2).asymmetry(), -1.0);
+        assert_eq!(T64::new(10.0, 0.3, 0.1).asymmetry(), 2.0);
+        assert_eq!(T64::new(10.0, 0.1, -0.3).asymmetry(), -0.5);
+        assert_eq!(T64::from(10.0).asymmetry(), 0.0);
+    }
+
+    #[test]
+    fn from_limits() {
+        assert_eq!(T64::from_limits(9.8, 10.2), Ok(T64::with_sym(10.0, 0.2)));
+        assert_eq!(T64::from_limits(5.0, 5.0), Ok(T64::from(5.0)));
+        // raw values in 0.1μ
+        assert_eq!(T64::from_limits(-3, 0), Ok(T64::new(-2, 2, -1)));
+        assert!(T64::from_limits(10.2, 9.8).is_err());
+    }
+
+    #[test]
+    fn tighter_looser() {
+        let a = T64::new(10.0, 0.1, -0.1);
+        let b = T64::new(12.0, 0.3, 0.0);
+        assert_eq!(a.tighter(b), a);
+        assert_eq!(b.tighter(a), a);
+        assert_eq!(a.looser(b), b);
+        assert_eq!(b.looser(a), b);
+
+        let c = T64::new(8.0, 0.0, -0.2);
+        assert_eq!(a.tighter(c), c);
+        assert_eq!(c.tighter(a), c);
+        assert_eq!(a.looser(c), a);
+        assert_eq!(c.looser(a), a);
+
+        let wide = T64::with_sym(10.0, 1.7);
+        let wider = T64::with_sym(10.0, 2.0);
+        assert_eq!(wide.tighter(wider), wide);
+        assert_eq!(wider.tighter(wide), wide);
+        assert_eq!(wide.looser(wider), wider);
+    }
+
+    #[test]
+    fn rereference() {
+        let t = T64::new(30.0, 0.2, -0.1);
+        let moved = t.shift_keeping_deviations(-2.5);
+        assert_eq!(moved, T64::new(27.5, 0.2, -0.1));
+        assert_eq!(moved.upper_limit(), t.upper_limit() - Myth32::from(2.5));
+
+        let kept = t.shift_keeping_limits(30.1).unwrap();
+        assert_eq!(kept, T64::new(30.1, 0.1, -0.2));
+        assert_eq!(kept.upper_limit(), t.upper_limit());
+        assert_eq!(kept.lower_limit(), t.lower_limit());
+
+        let (old, new) = (Myth32::from(5.0), Myth32::from(12.0));
+        let r = t.rereference(old, new);
+        assert_eq!(r.lower_limit(), t.lower_limit() + old - new);
+        assert_eq!(r.upper_limit(), t.upper_limit() + old - new);
+        assert_eq!(r.rereference(new, old), t);
+    }
+
+    #[test]
+    fn try_from_map() {
+        use std::collections::HashMap;
+        let map = |fields: &[(&str, f64)]| -> HashMap<String, f64> {
+            fields.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+        };
+        let full = map(&[("value", 10.0), ("plus", 0.05), ("minus", -0.2)]);
+        assert_eq!(T64::try_from(&full), Ok(T64::new(10.0, 0.05, -0.2)));
+        let aliases = map(&[("v", 10.0), ("p", 0.05), ("m", -0.2)]);
+        assert_eq!(T64::try_from(&aliases), Ok(T64::new(10.0, 0.05, -0.2)));
+        let partial = map(&[("value", 10.0), ("p", 0.1)]);
+        assert_eq!(T64::try_from(&partial), Ok(T64::with_sym(10.0, 0.1)));
+        let only_value = map(&[("v", 10.0)]);
+        assert_eq!(T64::try_from(&only_value), Ok(T64::from(10.0)));
+
+        assert!(T64::try_from(&map(&[("plus", 0.1)])).is_err());
+        assert!(T64::try_from(&map(&[("value", 1.0), ("v", 1.0)])).is_err());
+        assert!(T64::try_from(&map(&[("value", 1.0), ("width", 1.0)])).is_err());
+        assert!(T64::try_from(&map(&[("value", f64::NAN)])).is_err());
+        assert!(T64::try_from(&map(&[("value", 1.0), ("plus", 1e12)])).is_err());
+        assert!(matches!(
+            T64::try_from(&map(&[("value", 1.0), ("plus", 5.0)])),
+            Err(ToleranceError::Overflow(_))
+        ));
+        let wide = map(&[("v", 1.0), ("p", 3.0)]);
+        assert_eq!(T64::try_from(&wide), Ok(T64::with_sym(1.0, 3.0)));
+    }
+
+    #[test]
+    fn to_aligned_string() {
+        let rows = [
+            T64::with_sym(100.0, 0.05),
+            T64::new(12.5, 0.2, -0.1),
+            T64::new(-3.0, 0.0, -0.02),
+            T64::new(7.25, 0.03, 0.01),
+        ];
+        let lines: Vec<String> = rows.iter().map(|t| t.to_aligned_string(8, 2)).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "  100.00 ±0.05",
+                "   12.50 +0.20/-0.10",
+                "   -3.00 +0.00/-0.02",
+                "    7.25 +0.03/+0.01",
+            ]
+        );
+        assert_eq!(
+            T64::with_sym(1.0, 0.0001).to_aligned_string(0, 9),
+            "1.0000 ±0.0001"
+        );
+    }
+
+    #[test]
+    fn with_process_shift() {
+        let t = T64::new(10.0, 0.3, -0.1);
+        assert_eq!(t.with_process_shift(0.5), T64::new(10.1, 0.3, -0.1));
+        assert_eq!(t.with_process_shift(-1.0), T64::new(9.8, 0.3, -0.1));
+        assert_eq!(t.with_process_shift(-3.0), t.with_process_shift(-1.0));
+        assert_eq!(t.with_process_shift(0.0), t);
+        assert_eq!(
+            T64::new(1.0, 3, 0).with_process_shift(0.5),
+            T64::new(1.0001, 3, 0)
+        );
+        assert_eq!(
+            T64::with_sym(10.0, 1.7).with_process_shift(0.5),
+            T64::with_sym(10.85, 1.7)
+        );
+    }
+
+    #[test]
+    fn clamp_value() {
+        let t = T64::new(10.0, 0.2, -0.1);
+        assert_eq!(t.clamp_value(10.5, 12.0), T64::new(10.5, 0.2, -0.1));
+        assert_eq!(t.clamp_value(5.0, 8.0), T64::new(8.0, 0.2, -0.1));
+        assert_eq!(t.clamp_value(5.0, 12.0), t);
+    }
+
+    #[test]
+    fn midpoint_and_span() {
+        let t = T64::new(100.0, 0.05, -0.2);
+        assert_eq!(t.midpoint(), Myth32::from(99.925));
+        assert_eq!(t.span(), Myth32::from(0.25));
+        let sym = T64::with_sym(20.0, 0.1);
+        assert_eq!(sym.midpoint(), sym.value);
+        assert_eq!(sym.span(), Myth32::from(0.2));
+
+        let high = T64::new(10.0, 3.0, 0.5);
+        assert_eq!(high.midpoint(), Myth32::from(11.75));
+        assert_eq!(high.span(), Myth32::from(2.5));
+        assert_eq!(T64::with_sym(10.0, 1.7).span(), Myth32::from(3.4));
+    }
+
+    #[test]
+    fn round_to_standard() {
+        let computed = T64::new(10.0, Myth16(123), Myth16(-31));
+        let rounded = computed.round_to_standard();
+        assert_eq!(rounded, T64::new(10.0, 0.014, -0.004));
+        assert!(rounded.enfold(computed));
+        let standard = T64::new(10.0, 0.01, -0.005);
+        assert_eq!(standard.round_to_standard(), standard);
+        let coarse = computed.round_to_increments(&[Unit::STANDARD_INCREMENTS[0]]);
+        assert_eq!(coarse, T64::new(10.0, 0.02, -0.01));
+        assert!(coarse.enfold(computed));
+        assert_eq!(computed.round_to_increments(&[]), computed);
+    }
+
+    #[test]
+    fn contains() {
+        let t = T64::new(20.0, 0.1, -0.05);
+        assert!(t.contains(20.02));
+        assert!(t.contains(t.lower_limit()));
+        assert!(t.contains(t.upper_limit()));
+        assert!(!t.contains(Myth32(199_499)));
+        assert!(!t.contains(Myth32(201_001)));
+        let shifted = T64::new(20.0, 0.3, 0.1);
+        assert!(!shifted.contains(20.0));
+        assert!(shifted.contains(20.1));
+        assert!(shifted.contains(20.2));
+        assert!(shifted.contains(20.3));
+        assert!(!shifted.contains(20.4));
+    }
+
+    #[test]
+    fn simplify() {
+        let near = T64::new(10.0, Myth16(1_000), Myth16(-1_001));
+        assert_eq!(near.simplify(Myth16(1)), T64::with_sym(10.0, 0.1));
+        assert_eq!(near.simplify(Myth16::ZERO), near);
+        let asym = T64::new(10.0, 0.1, -0.2);
+        assert_eq!(asym.simplify(Myth16(1)), asym);
+
+        let below = T64::new(10.0, -0.1, -0.2);
+        assert_eq!(below.simplify(Myth16::from(0.5)), below);
+        let wide = T64::new(10.0, 1.7, Myth16(-16_999));
+        assert_eq!(wide.simplify(Myth16(1)), T64::with_sym(10.0, 1.7));
+    }
+
+    #[test]
+    fn intersection() {
+        let outer = T64::new(10.0, 0.2, -0.2);
+        let inner = T64::new(10.0, 0.1, 0.0);
+        assert_eq!(outer.intersection(&inner), Some(T64::with_sym(10.05, 0.05)));
+        assert_eq!(inner.intersection(&outer), outer.intersection(&inner));
+        let partial = T64::new(10.3, 0.0, -0.2);
+        assert_eq!(
+            outer.intersection(&partial),
+            Some(T64::with_sym(10.15, 0.05))
+        );
+        let touching = T64::new(10.3, 0.0, -0.1);
+        assert_eq!(outer.intersection(&touching), Some(T64::from(10.2)));
+        let disjoint = T64::with_sym(11.0, 0.1);
+        assert_eq!(outer.intersection(&disjoint), None);
+    }
+
+    #[test]
+    fn hull() {
+        let a = T64::new(10.0, 0.2, -0.1);
+        for b in [
+            T64::with_sym(10.2, 0.1),
+            T64::new(10.0, 0.1, 0.0),
+            T64::with_sym(11.0, 0.1),
+        ] {
+            let hull = a.hull(&b);
+            assert!(hull.enfold(a) && hull.enfold(b));
+            assert_eq!(hull, b.hull(&a));
+        }
+        assert_eq!(a.hull(&T64::with_sym(11.0, 0.1)), T64::with_sym(10.5, 0.6));
+        assert_eq!(a.hull(&a), T64::with_sym(10.05, 0.15));
+    }
+
+    #[test]
+    fn to_dual_string() {
+        let t = T64::new(100.0, 0.05, -0.2);
+        assert_eq!(
+            t.to_dual_string(Unit::MM, Unit::INCH),
+            "100.0 mm [3.937 in] +0.05/-0.2"
+        );
+        let t = T64::new(25.4, 0.0254, 0.0);
+        assert_eq!(
+            t.to_dual_string(Unit::INCH, Unit::MM),
+            "1.0 in [25.4 mm] +0.001/-0.0"
+        );
+        let t = T64::new(-12.0, -0.1, -0.3);
+        assert_eq!(
+            t.to_dual_string(Unit::CM, Unit::MM),
+            "-1.2 cm [-12.0 mm] -0.01/-0.03"
+        );
+    }
+
+    #[test]
+    fn narrow_t128() {
+        use crate::T128;
+        let wide = T128::new(100.0, 0.05, -0.2);
+        assert_eq!(T64::try_from(wide), Ok(T64::new(100.0, 0.05, -0.2)));
+        assert_eq!(T128::from(T64::try_from(wide).unwrap()), wide);
+        let too_long = T128::new(300_000.0, 0.05, -0.2);
+        assert!(matches!(
+            T64::try_from(too_long),
+            Err(ToleranceError::Overflow(_))
+        ));
+        let too_wide = T128::new(100.0, 5.0, -0.2);
+        assert!(matches!(
+            T64::try_from(too_wide),
+            Err(ToleranceError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn abs_and_signum() {
+        let positive = T64::new(10.0, 0.1, -0.2);
+        assert_eq!(positive.abs(), positive);
+        assert_eq!(positive.signum(), Myth32(1));
+        let negative = T64::new(-10.0, 0.1, -0.2);
+        assert_eq!(negative.abs(), T64::new(10.0, 0.2, -0.1));
+        assert_eq!(negative.abs(), negative.invert());
+        assert_eq!(negative.signum(), Myth32(-1));
+        let zero = T64::new(0.0, 0.1, -0.2);
+        assert_eq!(zero.abs(), zero);
+        assert_eq!(zero.signum(), Myth32::ZERO);
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!(T64::new(100.0, 0.2, -0.2) / 4, T64::new(25.0, 0.05, -0.05));
+        assert_eq!(
+            T64::new(10.0, 0.1, 0.0) / 3u8,
+            T64::new(Myth32(33_333), Myth16(333), Myth16::ZERO)
+        );
+        let t = T64::new(10.0, 0.1, -0.2);
+        let back = t / 7i32 * 7i32;
+        assert!((back.value - t.value).abs() < Myth32(7));
+        assert!((back.plus - t.plus).abs() < Myth16(7));
+        assert!((back.minus - t.minus).abs() < Myth16(7));
+    }
+
+    #[test]
+    fn contains_band_strict() {
+        let spec = T64::new(10.0, 0.2, -0.2);
+        let margin = T64::with_sym(10.0, 0.1);
+        assert!(spec.enfold(margin));
+        assert!(spec.contains_band_strict(margin));
+        let shared = T64::new(10.0, 0.2, 0.0);
+        assert!(spec.enfold(shared));
+        assert!(!spec.contains_band_strict(shared));
+        assert!(spec.enfold(spec));
+        assert!(!spec.contains_band_strict(spec));
+    }
+
+    #[test]
+    fn scale_tolerance() {
+        let t = T64::new(100.0, 0.05, -0.2);
+        assert_eq!(t.scale_tolerance(2.0), T64::new(100.0, 0.10, -0.4));
+        assert_eq!(t.scale_tolerance(0.5), T64::new(100.0, 0.025, -0.1));
+        assert_eq!(t.scale_tolerance(0.0), T64::from(100.0));
+        assert_eq!(
+            T64::new(10.0, 0.3, 0.1).scale_tolerance(1.0 / 3.0),
+            T64::new(10.0, 0.1, Myth16(333))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_tolerance_negative() {
+        let _ = T64::new(100.0, 0.05, -0.2).scale_tolerance(-1.0);
+    }
+
+    #[test]
+    fn to_symmetric() {
+        let t = T64::new(100.0, 0.3, -0.1);
+        assert!(!t.is_symmetric());
+        let sym = t.to_symmetric();
+        assert!(sym.is_symmetric());
+        assert_eq!(sym, T64::with_sym(100.1, 0.2));
+        assert_eq!(sym.lower_limit(), t.lower_limit());
+        assert_eq!(sym.upper_limit(), t.upper_limit());
+        let odd = T64::new(10.0, Myth16(3), Myth16::ZERO).to_symmetric();
+        assert!(odd.enfold(T64::new(10.0, Myth16(3), Myth16::ZERO)));
+        assert_eq!(odd.width(), Myth32(4));
+
+        let wide = T64::with_sym(0.0, 1.7);
+        assert_eq!(wide.to_symmetric(), wide);
+        let shifted = T64::new(0.0, 3.0, -0.5).to_symmetric();
+        assert_eq!(shifted, T64::with_sym(1.25, 1.75));
+        assert!(T64::with_sym(5.0, 0.1).is_symmetric());
+    }
+
+    #[test]
+    fn unilateral() {
+        let upper = T64::upper_only(10.0, 0.2);
+        assert_eq!(upper, T64::new(10.0, 0.2, 0.0));
+        assert_eq!(upper.lower_limit(), Myth32::from(10.0));
+        assert_eq!(upper.upper_limit(), Myth32::from(10.2));
+        assert!(upper.is_unilateral());
+        let lower = T64::lower_only(10.0, -0.2);
+        assert_eq!(lower.lower_limit(), Myth32::from(9.8));
+        assert_eq!(lower.upper_limit(), Myth32::from(10.0));
+        assert!(lower.is_unilateral());
+        assert!(!T64::with_sym(10.0, 0.2).is_unilateral());
+        assert!(!T64::from(10.0).is_unilateral());
+    }
+
+    #[test]
+    #[should_panic]
+    fn upper_only_negative() {
+        let _ = T64::upper_only(10.0, -0.2);
+    }
+
+    #[test]
+    fn parse_parentheses() {
+        assert_eq!("100.0 (\u{b1}0.1)".parse(), Ok(T64::with_sym(100.0, 0.1)));
+        assert_eq!("100.0 (+0.2/-0.1)".parse(), Ok(T64::new(100.0, 0.2, -0.1)));
+        assert_eq!("100.0(+0.2 -0.1) ".parse(), Ok(T64::new(100.0, 0.2, -0.1)));
+        assert_eq!("100.0 \u{b1}0.1".parse(), Ok(T64::with_sym(100.0, 0.1)));
+        assert!("100.0 (+0.2/-0.1".parse::<T64>().is_err());
+        assert!("100.0 +0.2/-0.1)".parse::<T64>().is_err());
+        assert!("100.0 )+0.2/-0.1(".parse::<T64>().is_err());
+        assert!("(100.0) +0.2/-0.1".parse::<T64>().is_err());
+        assert!("100 \u{b1}-0.1".parse::<T64>().is_err());
+        assert!("100 (\u{b1} +0.1)".parse::<T64>().is_err());
+        assert!("100 +/--0.1".parse::<T64>().is_err());
+        assert!("100 +--0.1".parse::<T64>().is_err());
+    }
+
+    #[test]
+    fn expected_value() {
+        let t = T64::new(100.0, 0.05, -0.2);
+        assert_eq!(t.expected_value(), Myth32::from(99.925));
+        assert_eq!(t.expected_value_normal(), Myth32::from(100.0));
+        let sym = T64::with_sym(100.0, 0.1);
+        assert_eq!(sym.expected_value(), sym.expected_value_normal());
+    }
+
+    #[test]
+    fn with_safety_factor() {
+        let t = T64::new(10.0, 0.2, -0.08);
+        let safe = T64::with_safety_factor(t, 1.25);
+        assert_eq!(safe, T64::new(10.0, 0.25, -0.1));
+        assert_eq!(safe.value, t.value);
+        assert!(safe.enfold(t));
+        assert_eq!(T64::with_safety_factor(t, -1.0), T64::from(10.0));
+        let huge = T64::with_safety_factor(t, 1e30);
+        assert_eq!((huge.plus, huge.minus), (Myth16::MAX, Myth16::MIN));
+    }
+
+    #[test]
+    fn round_value() {
+        let t = T64::new(Myth32(123_456), 0.05, -0.2);
+        assert_eq!(t.round_value(Unit::MM), T64::new(12.0, 0.05, -0.2));
+        assert_eq!(t.floor_value(Unit::MY), T64::new(12.345, 0.05, -0.2));
+        assert_eq!(t.ceil_value(Unit::MY), T64::new(12.346, 0.05, -0.2));
+    }
+
+    #[test]
+    fn limits() {
+        let (lower, upper) = T64::new(100.0, 0.05, -0.2).limits();
+        assert_eq!(lower.value, Myth32::from(99.8));
+        assert_eq!(upper.value, Myth32::from(100.05));
+        for limit in [lower, upper] {
+            assert_eq!((limit.plus, limit.minus), (Myth16::ZERO, Myth16::ZERO));
+        }
+    }
+
+    #[test]
+    fn deviations_mm() {
+        assert_eq!(T64::new(100.0, 0.05, -0.2).deviations_mm(), (0.05, -0.2));
+        assert_eq!(T64::new(100.0, 0.3, 0.1).deviations_mm(), (0.3, 0.1));
+        assert_eq!(T64::new(100.0, -0.1, -0.3).deviations_mm(), (-0.1, -0.3));
+    }
+
+    #[test]
+    fn enfold() {
+        let outer = T64::new(10.0, 0.2, -0.2);
+        assert!(outer.enfold(T64::new(10.0, 0.1, -0.2)));
+        assert!(outer.enfold(outer));
+        assert!(!outer.enfold(T64::new(10.1, 0.2, -0.2)));
+        assert!(!T64::new(10.0, 0.1, -0.2).enfold(outer));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn embrace_like_enfold() {
+        let outer = T64::new(10.0, 0.2, -0.2);
+        for other in [T64::new(10.0, 0.1, -0.2), outer, T64::new(10.1, 0.2, -0.2)] {
+            assert_eq!(outer.embrace(other), outer.enfold(other));
+            assert_eq!(other.embrace(outer), other.enfold(outer));
+        }
+    }
+
     #[test]
     fn error() {
         use ToleranceError::ParseError;
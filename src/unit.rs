@@ -1,4 +1,7 @@
 use std::ops::{Deref, Mul};
+use std::str::FromStr;
+
+use crate::error::ToleranceError;
 
 /// # Unit-conversation helper.
 ///
@@ -19,6 +22,12 @@ impl Unit {
     /// Centimeter `1 cm = 10 mm = 10_000 μ` the equivalent to `potency(5)`.
     pub const CM: Unit = Unit(10 * Unit::MM.0);
 
+    /// Micron, an alias of `MY`.
+    pub const MICRON: Unit = Unit::MY;
+
+    /// Thou or mil `1 thou = 0.001 in = 25.4 μ`.
+    pub const THOU: Unit = Unit(254);
+
     /// Inch `1 in = 25.4 mm = 25_400 μ`.
     pub const INCH: Unit = Unit(25_400 * Unit::MY.0);
 
@@ -28,6 +37,9 @@ impl Unit {
     /// Yard `1 yd = 3 ft = 914.4 mm = 914_400 μ`.
     pub const YD: Unit = Unit(3 * Unit::FT.0);
 
+    /// Decimeter `1 dm = 10 cm = 100 mm` the equivalent to `potency(6)`.
+    pub const DM: Unit = Unit(10 * Unit::CM.0);
+
     /// Meter `100 cm = 1_000 mm = 1_000_000 μ` the equivalent to `potency(7)`.
     pub const METER: Unit = Unit(1_000 * Unit::MM.0);
 
@@ -36,6 +48,10 @@ impl Unit {
 
     /// Mile `1 mi = 1760 yd = 1609.344 m = 1_609_344_000 μ`.
     pub const MILE: Unit = Unit(1760 * Unit::YD.0);
+
+    /// The usual shop increments for tolerances, coarsest first: `0.01 mm`, `0.005 mm`
+    /// and `0.002 mm`.
+    pub const STANDARD_INCREMENTS: [Unit; 3] = [Unit(100), Unit(50), Unit(20)];
 }
 
 impl Unit {
@@ -52,6 +68,92 @@ impl Unit {
     }
 }
 
+/// The unit suffixes understood while parsing.
+const SUFFIXES: [(&str, Unit); 15] = [
+    ("mm", Unit::MM),
+    ("cm", Unit::CM),
+    ("dm", Unit::DM),
+    ("m", Unit::METER),
+    ("km", Unit::KM),
+    ("\u{b5}m", Unit::MY),
+    ("um", Unit::MY),
+    ("\u{3bc}m", Unit::MY),
+    ("\u{b5}", Unit::MY),
+    ("\u{3bc}", Unit::MY),
+    ("thou", Unit::THOU),
+    ("mil", Unit::THOU),
+    ("in", Unit::INCH),
+    ("ft", Unit::FT),
+    ("yd", Unit::YD),
+];
+
+impl Unit {
+    /// Returns the `Unit` of a suffix like `"mm"`, `"in"` or `"µm"`.
+    ///
+    /// ```rust
+    /// # use tolerance::Unit;
+    /// assert_eq!(Unit::from_suffix("in"), Some(Unit::INCH));
+    /// assert_eq!(Unit::from_suffix("parsec"), None);
+    /// ```
+    #[must_use]
+    pub fn from_suffix(suffix: &str) -> Option<Unit> {
+        SUFFIXES
+            .iter()
+            .find(|(s, _)| *s == suffix)
+            .map(|(_, unit)| *unit)
+    }
+
+    /// Returns the symbol of this `Unit` like `"mm"` or `"in"`, `None` for units without one.
+    ///
+    /// ```rust
+    /// # use tolerance::Unit;
+    /// assert_eq!(Unit::INCH.symbol(), Some("in"));
+    /// assert_eq!(Unit::MY.symbol(), Some("\u{b5}m"));
+    /// assert_eq!(Unit::potency(2).symbol(), None);
+    /// ```
+    #[must_use]
+    pub fn symbol(&self) -> Option<&'static str> {
+        SUFFIXES
+            .iter()
+            .find(|(_, unit)| unit == self)
+            .map(|(s, _)| *s)
+    }
+}
+
+/// Names understood by `FromStr` beside the suffixes.
+const NAMES: [(&str, Unit); 4] = [
+    ("inch", Unit::INCH),
+    ("mi", Unit::MILE),
+    ("mile", Unit::MILE),
+    ("micron", Unit::MY),
+];
+
+/// Parses a unit name like `"mm"`, `"Inch"` or `"micron"`, ignoring the case.
+///
+/// ```rust
+/// # use tolerance::{Myth64, Unit};
+/// let unit: Unit = "in".parse().unwrap();
+/// assert_eq!(Myth64::from(50.8).as_unit(unit), 2.0);
+/// ```
+impl FromStr for Unit {
+    type Err = ToleranceError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name = value.trim().to_lowercase();
+        Unit::from_suffix(&name)
+            .or_else(|| NAMES.iter().find(|(n, _)| *n == name).map(|(_, u)| *u))
+            .ok_or_else(|| ToleranceError::ParseError(format!("Unknown unit '{value}'!")))
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = ToleranceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Unit::from_str(value)
+    }
+}
+
 impl Deref for Unit {
     type Target = i64;
 
@@ -93,6 +195,7 @@ unit_from_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
 #[cfg(test)]
 mod should {
     use super::Unit;
+    use crate::error::ToleranceError;
 
     #[test]
     fn multiply_with_number() {
@@ -104,9 +207,52 @@ mod should {
     fn be_equal_dyn() {
         assert_eq!(Unit::MY, Unit::potency(1));
         assert_eq!(Unit::MM, Unit::potency(4));
+        assert_eq!(Unit::DM, Unit::potency(6));
         assert_eq!(Unit::potency(7), Unit::METER);
     }
 
+    #[test]
+    fn from_suffix() {
+        assert_eq!(Unit::from_suffix("mm"), Some(Unit::MM));
+        assert_eq!(Unit::from_suffix("m"), Some(Unit::METER));
+        assert_eq!(Unit::from_suffix("\u{b5}m"), Some(Unit::MY));
+        assert_eq!(Unit::from_suffix("yd"), Some(Unit::YD));
+        assert_eq!(Unit::from_suffix("MM"), None);
+        assert_eq!(Unit::from_suffix(""), None);
+    }
+
+    #[test]
+    fn thou() {
+        assert_eq!(254, 1 * Unit::THOU);
+        assert_eq!(Unit::INCH, Unit::THOU * 1000);
+        assert_eq!(crate::Myth64::from(0.0254).as_unit(Unit::THOU), 1.0);
+        assert_eq!(Unit::from_suffix("mil"), Some(Unit::THOU));
+        assert_eq!(Unit::MICRON, Unit::MY);
+    }
+
+    #[test]
+    fn from_str() {
+        let parse = |s: &str| s.parse::<Unit>().unwrap();
+        assert_eq!(parse("mm"), Unit::MM);
+        assert_eq!(parse("CM"), Unit::CM);
+        assert_eq!(parse("m"), Unit::METER);
+        assert_eq!(parse("Km"), Unit::KM);
+        assert_eq!(parse("in"), Unit::INCH);
+        assert_eq!(parse("Inch"), Unit::INCH);
+        assert_eq!(parse("ft"), Unit::FT);
+        assert_eq!(parse("yd"), Unit::YD);
+        assert_eq!(parse("mi"), Unit::MILE);
+        assert_eq!(parse("MILE"), Unit::MILE);
+        assert_eq!(parse("um"), Unit::MY);
+        assert_eq!(parse("\u{b5}"), Unit::MY);
+        assert_eq!(parse(" micron "), Unit::MY);
+        assert_eq!(Unit::try_from("ft"), Ok(Unit::FT));
+        assert!(matches!(
+            "parsec".parse::<Unit>(),
+            Err(ToleranceError::ParseError(_))
+        ));
+    }
+
     #[test]
     fn be_const() {
         assert_eq!(3_048_000, Unit::FT.0);